@@ -0,0 +1,30 @@
+/// Enum describing the axes of a hexagon plane
+///
+/// Reflecting across the Q, R or S axis keeps that coordinate and swaps the
+/// other two. Reflecting across an anti axis negates all three coordinates and
+/// then swaps the other two, which is the reflection across the line at a
+/// right angle to the matching axis.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Axis {
+  /// The line through the east northeast and west southwest diagonals
+  Q,
+  /// The line through the north and south diagonals
+  R,
+  /// The line through the east southeast and west northwest diagonals
+  S,
+  /// The line through the southeast and northwest directions
+  AntiQ,
+  /// The line through the east and west directions
+  AntiR,
+  /// The line through the southwest and northeast directions
+  AntiS,
+}
+
+impl Axis {
+
+  /// Get a vector of axes
+  pub fn to_vec() -> Vec<Axis> {
+    vec![ Axis::Q, Axis::R, Axis::S, Axis::AntiQ, Axis::AntiR, Axis::AntiS ]
+  }
+
+}
@@ -0,0 +1,34 @@
+/// Enum describing the corners owned by a point
+///
+/// Every corner of the grid is shared by three hexagons, so each hexagon only
+/// needs to own two of its six corners to describe all of them.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Corner {
+  North,
+  South,
+}
+
+impl Corner {
+
+  /// Return the opposite corner
+  pub fn opposite(&self) -> Corner {
+
+    match *self {
+      Corner::North => Corner::South,
+      Corner::South => Corner::North,
+    }
+
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn opposite() {
+    assert!(Corner::North.opposite() == Corner::South);
+    assert!(Corner::South.opposite() == Corner::North);
+  }
+}
@@ -0,0 +1,76 @@
+use Diagonal::*;
+
+/// Enum describing diagonal positions in relation to a point
+///
+/// A diagonal lies between two neighboring directions. Traveling one unit
+/// diagonally is the same as traveling one unit in each of those directions.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Diagonal {
+  EastSoutheast,
+  South,
+  WestSouthwest,
+  WestNorthwest,
+  North,
+  EastNortheast,
+}
+
+impl Diagonal {
+
+  /// Get a vector of diagonals
+  pub fn to_vec() -> Vec<Diagonal> {
+    vec![ EastSoutheast, South, WestSouthwest, WestNorthwest, North, EastNortheast ]
+  }
+
+  /// Return the opposite diagonal
+  pub fn opposite(&self) -> Diagonal {
+    self.rotate(3)
+  }
+
+  /// Rotate the diagonal
+  ///
+  /// Positive rotations are clockwise. Six rotations bring a diagonal back to
+  /// the starting position.
+  pub fn rotate(&self, times: i32) -> Diagonal {
+    let diagonals: Vec<Diagonal> = Diagonal::to_vec();
+    let index = diagonals.iter().position(|diagonal| diagonal == self).unwrap();
+
+    diagonals[(index as i32 + times).rem_euclid(6) as usize]
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn to_vec() {
+    let vec: Vec<Diagonal> = Diagonal::to_vec();
+
+    assert!(vec[0] == EastSoutheast);
+    assert!(vec[1] == South);
+    assert!(vec[2] == WestSouthwest);
+    assert!(vec[3] == WestNorthwest);
+    assert!(vec[4] == North);
+    assert!(vec[5] == EastNortheast);
+    assert!(vec.len() == 6);
+  }
+
+  #[test]
+  fn opposite() {
+    assert!(EastSoutheast.opposite() == WestNorthwest);
+    assert!(South.opposite()         == North);
+    assert!(WestSouthwest.opposite() == EastNortheast);
+    assert!(WestNorthwest.opposite() == EastSoutheast);
+    assert!(North.opposite()         == South);
+    assert!(EastNortheast.opposite() == WestSouthwest);
+  }
+
+  #[test]
+  fn rotate() {
+    assert!(EastSoutheast.rotate(1)  == South);
+    assert!(EastSoutheast.rotate(-1) == EastNortheast);
+    assert!(North.rotate(8)          == EastSoutheast);
+    assert!(North.rotate(-6)         == North);
+  }
+}
@@ -1,11 +1,16 @@
 use std::borrow::Borrow;
+use std::convert::TryFrom;
 
+use enums::{Axis, DirectionError};
 use structs::Point;
+use traits::distance::Base;
+use traits::reflect::Reflect;
+use traits::travel::Travel;
 
 use Direction::*;
 
 /// Enum describing positions in relation to a point
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Direction {
   East,
   Southeast,
@@ -24,6 +29,87 @@ impl Direction {
     vec![ East, Southeast, Southwest, West, Northwest, Northeast, Up, Down ]
   }
 
+  /// Get a vector of the directions at the same height, clockwise from east
+  pub fn planar() -> Vec<Direction> {
+    vec![ East, Southeast, Southwest, West, Northwest, Northeast ]
+  }
+
+  /// Get a vector of the directions which change height
+  pub fn vertical() -> Vec<Direction> {
+    vec![ Up, Down ]
+  }
+
+  /// Get a direction by its index in `to_vec`
+  pub fn from_index(index: usize) -> Option<Direction> {
+    Direction::to_vec().get(index).cloned()
+  }
+
+  /// Return the index of the direction in `to_vec`
+  pub fn index(&self) -> usize {
+    match *self {
+      East      => 0,
+      Southeast => 1,
+      Southwest => 2,
+      West      => 3,
+      Northwest => 4,
+      Northeast => 5,
+      Up        => 6,
+      Down      => 7,
+    }
+  }
+
+  /// Return whether the direction changes height
+  pub fn is_vertical(&self) -> bool {
+    self.index() > 5
+  }
+
+  /// Rotate the direction by sixths of a full turn
+  ///
+  /// Positive rotations are clockwise, as with `Rotate::rotate`. Six rotations
+  /// bring a direction back to the starting position. Up and down are not
+  /// changed by rotation.
+  pub fn rotate(&self, times: i32) -> Direction {
+    if self.is_vertical() {
+      return *self;
+    }
+
+    let index = (self.index() as i32 + times).rem_euclid(6);
+
+    Direction::planar()[index as usize]
+  }
+
+  /// Reflect the direction across an axis
+  ///
+  /// Up and down are not changed by reflection.
+  pub fn reflect(&self, axis: &Axis) -> Direction {
+    let origin: Point = Point(0, 0, 0);
+    let reflected: Point = origin.travel(self, 1).reflect(&origin, axis);
+
+    Direction::try_from((&origin, &reflected)).unwrap()
+  }
+
+  /// Count the rotations needed to turn from one direction to another
+  ///
+  /// The shortest turn is returned, so the result is between -2 and 3, where
+  /// positive turns are clockwise. Directions which change height can only
+  /// turn to themselves.
+  pub fn turns(&self, other: &Direction) -> Option<i32> {
+    if self == other {
+      return Some(0);
+    }
+
+    if self.is_vertical() || other.is_vertical() {
+      return None;
+    }
+
+    let turns = (other.index() as i32 - self.index() as i32).rem_euclid(6);
+
+    match turns > 3 {
+      true => Some(turns - 6),
+      false => Some(turns),
+    }
+  }
+
   /// Return the opposite direction
   pub fn opposite(&self) -> Direction {
 
@@ -40,28 +126,71 @@ impl Direction {
 
   }
 
-}
+  /// Return the direction closest to the provided vector
+  ///
+  /// A planar step and a vertical step are considered the same length, so
+  /// the height is compared with the steps along the closest planar
+  /// direction. Ties are broken in favor of planar directions and then in the
+  /// order of `to_vec`. The zero vector has no direction.
+  pub fn approximate<T: Borrow<Point>>(vector: &T) -> Option<Direction> {
+    let vector: &Point = vector.borrow();
+    let &Point(q, r, t) = vector;
 
-impl <'a, 'b, T> From<(&'a T, &'b T)> for Direction where T: Borrow<Point> {
+    if q == 0 && r == 0 && t == 0 {
+      return None;
+    }
+
+    let origin: Point = Point(0, 0, 0);
+    let dot = |direction: &Direction| {
+      let unit: Point = origin.travel(direction, 1);
+
+      q * unit.q() + r * unit.r() + vector.s() * unit.s()
+    };
 
-  /// Get the direction from one point to another
-  fn from((p0, p1): (&'a T, &'b T)) -> Direction {
-    let Point(q, r, t) = p1.borrow() - p0.borrow();
+    let mut best: Direction = East;
+
+    for direction in &Direction::planar() {
+      if dot(direction) > dot(&best) {
+        best = *direction;
+      }
+    }
 
-    match t.signum() {
-       1 => return Up,
-      -1 => return Down,
-       _ => (),
+    if 2 * t.abs() > dot(&best) {
+      return Some(if t > 0 { Up } else { Down });
     }
 
-    return match (q.signum(), r.signum()) {
-      ( 1,  0) => East,
-      ( 0,  1) => Southeast,
-      (-1,  1) => Southwest,
-      (-1,  0) => West,
-      ( 0, -1) => Northwest,
-      ( 1, -1) => Northeast,
-      _ => panic!(),
+    Some(best)
+  }
+
+}
+
+impl <'a, 'b, T> TryFrom<(&'a T, &'b T)> for Direction where T: Borrow<Point> {
+  type Error = DirectionError;
+
+  /// Get the direction from one point to its neighbor
+  fn try_from((p0, p1): (&'a T, &'b T)) -> Result<Direction, DirectionError> {
+    let diff: Point = p1.borrow() - p0.borrow();
+    let Point(q, r, t) = diff;
+    let s = diff.s();
+
+    let direction = match (q, r, t) {
+      (0, 0, 0) => return Err(DirectionError::Coincident),
+      (0, 0, _) if t > 0 => Up,
+      (0, 0, _) => Down,
+      (_, _, 0) if q == 0 || r == 0 || s == 0 => match (q.signum(), r.signum()) {
+        ( 1,  0) => East,
+        ( 0,  1) => Southeast,
+        (-1,  1) => Southwest,
+        (-1,  0) => West,
+        ( 0, -1) => Northwest,
+        _        => Northeast,
+      },
+      _ => return Err(DirectionError::Diagonal),
+    };
+
+    match diff.base_distance(&Point(0, 0, 0)) + t.abs() {
+      1 => Ok(direction),
+      _ => Err(DirectionError::NotAdjacent),
     }
   }
 
@@ -86,6 +215,94 @@ mod tests {
     assert!(vec.len() == 8)
   }
 
+  #[test]
+  fn planar() {
+    let vec: Vec<Direction> = Direction::planar();
+
+    assert!(vec == Direction::to_vec()[..6].to_vec());
+    assert!(vec.iter().all(|direction| !direction.is_vertical()));
+  }
+
+  #[test]
+  fn vertical() {
+    let vec: Vec<Direction> = Direction::vertical();
+
+    assert!(vec == vec![ Up, Down ]);
+    assert!(vec.iter().all(Direction::is_vertical));
+  }
+
+  #[test]
+  fn index() {
+    for (index, direction) in Direction::to_vec().iter().enumerate() {
+      assert!(index == direction.index());
+      assert!(Some(*direction) == Direction::from_index(index));
+    }
+
+    assert!(Direction::from_index(8).is_none());
+  }
+
+  #[test]
+  fn rotate() {
+    assert!(East.rotate(1)      == Southeast);
+    assert!(East.rotate(-1)     == Northeast);
+    assert!(Northwest.rotate(3) == Southeast);
+    assert!(Southwest.rotate(8) == Northwest);
+    assert!(West.rotate(-6)     == West);
+    assert!(Up.rotate(2)        == Up);
+    assert!(Down.rotate(-1)     == Down);
+  }
+
+  #[test]
+  fn rotate_matches_point() {
+    use traits::rotate::Rotate;
+
+    let origin: Point = Point(0, 0, 0);
+
+    for direction in &Direction::planar() {
+      let point: Point = origin.travel(direction, 1).rotate(&origin, 2);
+
+      assert!(point == origin.travel(&direction.rotate(2), 1));
+    }
+  }
+
+  #[test]
+  fn reflect() {
+    assert!(East.reflect(&Axis::Q)          == Northeast);
+    assert!(East.reflect(&Axis::R)          == West);
+    assert!(Northwest.reflect(&Axis::S)     == West);
+    assert!(Southeast.reflect(&Axis::AntiQ) == Southeast);
+    assert!(Northeast.reflect(&Axis::AntiR) == Southeast);
+    assert!(West.reflect(&Axis::AntiS)      == Southeast);
+    assert!(Up.reflect(&Axis::R)            == Up);
+  }
+
+  #[test]
+  fn reflect_matches_point() {
+    let origin: Point = Point(0, 0, 0);
+    let center: Point = Point(1, 2, 5);
+
+    for axis in &Axis::to_vec() {
+      for direction in &Direction::to_vec() {
+        let p0: Point = origin.reflect(&center, axis);
+        let p1: Point = origin.travel(direction, 1).reflect(&center, axis);
+
+        assert!(Ok(direction.reflect(axis)) == Direction::try_from((&p0, &p1)));
+      }
+    }
+  }
+
+  #[test]
+  fn turns() {
+    assert!(Some(0)  == East.turns(&East));
+    assert!(Some(1)  == East.turns(&Southeast));
+    assert!(Some(-1) == East.turns(&Northeast));
+    assert!(Some(3)  == Northeast.turns(&Southwest));
+    assert!(Some(-2) == Southeast.turns(&Northeast));
+    assert!(Some(0)  == Up.turns(&Up));
+    assert!(East.turns(&Up).is_none());
+    assert!(Down.turns(&Up).is_none());
+  }
+
   #[test]
   fn opposite() {
     assert!(East.opposite()      == West);
@@ -99,7 +316,7 @@ mod tests {
   }
 
   #[test]
-  fn from() {
+  fn try_from() {
     let point:     Point = Point(1, 2, 5);
     let east:      Point = Point(2, 2, 5);
     let southeast: Point = Point(1, 3, 5);
@@ -110,15 +327,53 @@ mod tests {
     let up:        Point = Point(1, 2, 6);
     let down:      Point = Point(1, 2, 4);
 
-    let from = Direction::from;
+    let from = Direction::try_from;
+
+    assert!(Ok(East)      == from((&point, &east)));
+    assert!(Ok(Southeast) == from((&point, &southeast)));
+    assert!(Ok(Southwest) == from((&point, &southwest)));
+    assert!(Ok(West)      == from((&point, &west)));
+    assert!(Ok(Northwest) == from((&point, &northwest)));
+    assert!(Ok(Northeast) == from((&point, &northeast)));
+    assert!(Ok(Up)        == from((&point, &up)));
+    assert!(Ok(Down)      == from((&point, &down)));
+  }
+
+  #[test]
+  fn try_from_error() {
+    let point: Point = Point(1, 2, 5);
+    let from = Direction::try_from;
+
+    assert!(Err(DirectionError::Coincident)  == from((&point, &point)));
+    assert!(Err(DirectionError::NotAdjacent) == from((&point, &Point(3, 2, 5))));
+    assert!(Err(DirectionError::NotAdjacent) == from((&point, &Point(1, 2, 3))));
+    assert!(Err(DirectionError::Diagonal)    == from((&point, &Point(2, 3, 5))));
+    assert!(Err(DirectionError::Diagonal)    == from((&point, &Point(2, 2, 6))));
+  }
 
-    assert!(East      == from((&point, &east)));
-    assert!(Southeast == from((&point, &southeast)));
-    assert!(Southwest == from((&point, &southwest)));
-    assert!(West      == from((&point, &west)));
-    assert!(Northwest == from((&point, &northwest)));
-    assert!(Northeast == from((&point, &northeast)));
-    assert!(Up        == from((&point, &up)));
-    assert!(Down      == from((&point, &down)));
+  #[test]
+  fn approximate() {
+    assert!(Some(East)      == Direction::approximate(&Point( 5,  1, 0)));
+    assert!(Some(Southeast) == Direction::approximate(&Point(-1,  4, 2)));
+    assert!(Some(Southwest) == Direction::approximate(&Point(-3,  3, 0)));
+    assert!(Some(West)      == Direction::approximate(&Point(-2,  0, 1)));
+    assert!(Some(Northwest) == Direction::approximate(&Point( 1, -4, 0)));
+    assert!(Some(Northeast) == Direction::approximate(&Point( 2, -3, 0)));
+    assert!(Some(Up)        == Direction::approximate(&Point( 1,  0, 2)));
+    assert!(Some(Down)      == Direction::approximate(&Point( 0,  0, -1)));
+    assert!(Direction::approximate(&Point(0, 0, 0)).is_none());
+  }
+
+  #[test]
+  fn approximate_tie() {
+    assert!(Some(East) == Direction::approximate(&Point(1, 1, 0)));
+    assert!(Some(East) == Direction::approximate(&Point(1, 0, 1)));
+  }
+
+  #[test]
+  fn approximate_diagonal() {
+    assert!(Some(Up)   == Direction::approximate(&Point( 3,  3,  5)));
+    assert!(Some(Down) == Direction::approximate(&Point(-3, -3, -5)));
+    assert!(Some(East) == Direction::approximate(&Point( 3,  3,  4)));
   }
 }
@@ -0,0 +1,27 @@
+use std::error::Error;
+use std::fmt;
+
+/// Enum describing why two points have no direction between them
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DirectionError {
+  /// The points are the same point
+  Coincident,
+  /// The points are in a straight line but are not neighbors
+  NotAdjacent,
+  /// The points are not in a straight line along any direction
+  Diagonal,
+}
+
+impl fmt::Display for DirectionError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let message = match *self {
+      DirectionError::Coincident  => "points are the same point",
+      DirectionError::NotAdjacent => "points are not neighbors",
+      DirectionError::Diagonal    => "points are not in line along a direction",
+    };
+
+    f.write_str(message)
+  }
+}
+
+impl Error for DirectionError {}
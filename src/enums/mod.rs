@@ -1,4 +1,16 @@
+mod axis;
+mod corner;
+mod diagonal;
 mod direction;
+mod direction_error;
+mod wrap;
+mod zone;
 
+pub use self::axis::Axis;
+pub use self::corner::Corner;
+pub use self::diagonal::Diagonal;
 pub use self::direction::Direction;
+pub use self::direction_error::DirectionError;
 
+pub use self::wrap::Wrap;
+pub use self::zone::Zone;
@@ -0,0 +1,299 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+use enums::Direction;
+use fns::search::flood;
+use structs::{Chunks, Point, Prism};
+use traits::IsPointMap;
+use traits::distance::{Base as BaseDistance, Distance};
+use traits::line::Line;
+use traits::range::{Base as BaseRange, Range};
+use traits::travel::Travel;
+
+/// Enum describing a world which wraps around its edges
+///
+/// Every point outside of the world is treated as the point inside of it
+/// found by repeatedly moving across the world. Heights never wrap. Maps used
+/// with a wrapping world are keyed by normalized points.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Wrap {
+  /// A world of points with q from zero below a width and r from zero below a
+  /// height
+  ///
+  /// The width and height must be positive.
+  Rhombus(i32, i32),
+  /// A world of points within a radius of the origin
+  ///
+  /// The copies of the world are the chunks of `Chunks` with the same radius,
+  /// so the radius must not be negative.
+  Hexagon(i32),
+}
+
+impl Wrap {
+
+  /// Find the point inside the world matching a point
+  ///
+  /// Panics if the dimensions of the world are out of range.
+  pub fn normalize<T: Borrow<Point>>(&self, point: &T) -> Point {
+    let &Point(q, r, t) = point.borrow();
+
+    match *self {
+      Wrap::Rhombus(width, height) => {
+        assert!(width > 0 && height > 0, "rhombus dimensions must be positive");
+
+        Point(q.rem_euclid(width), r.rem_euclid(height), t)
+      },
+      Wrap::Hexagon(radius) => &Chunks(radius).local(point) + &Point(0, 0, t),
+    }
+  }
+
+  /// Find the copy of a point outside the world which is closest to another
+  ///
+  /// Measuring or drawing from the first point to the result takes the short
+  /// way across the edges of the world.
+  pub fn image<T: Borrow<Point>, U: Borrow<Point>>(&self, from: &T, to: &U) -> Point {
+    let from: &Point = from.borrow();
+    let delta: Point = self.normalize(&(to.borrow() - from));
+
+    let mut closest: Point = delta;
+
+    for a in -1 ..= 1 {
+      for b in -1 ..= 1 {
+        let moved: Point = &delta + &self.offset(a, b);
+
+        if moved.base_distance(&Point(0, 0, 0)) < closest.base_distance(&Point(0, 0, 0)) {
+          closest = moved;
+        }
+      }
+    }
+
+    from + &closest
+  }
+
+  /// Travel from a point, wrapping around the edges of the world
+  pub fn travel<T: Borrow<Point>>(
+    &self,
+    point: &T,
+    direction: &Direction,
+    distance: i32,
+  ) -> Point {
+    self.normalize(&point.borrow().travel(direction, distance))
+  }
+
+  /// Find the planar distance between two points the short way around
+  pub fn base_distance<T: Borrow<Point>, U: Borrow<Point>>(&self, from: &T, to: &U) -> i32 {
+    from.borrow().base_distance(&self.image(from, to))
+  }
+
+  /// Find the distance between two points the short way around
+  pub fn distance<T: Borrow<Point>, U: Borrow<Point>>(&self, from: &T, to: &U) -> i32 {
+    from.borrow().distance(&self.image(from, to))
+  }
+
+  /// Find the points of the same height within a range of a point
+  pub fn base_range<T: Borrow<Point>>(&self, point: &T, range: i32) -> HashSet<Point> {
+    point.borrow()
+      .base_range(range)
+      .iter()
+      .map(|other| self.normalize(other))
+      .collect()
+  }
+
+  /// Find the points within a range of a point
+  pub fn range<T: Borrow<Point>>(&self, point: &T, range: i32) -> HashSet<Point> {
+    point.borrow()
+      .range(range)
+      .iter()
+      .map(|other| self.normalize(other))
+      .collect()
+  }
+
+  /// Find the points on the shortest line between two points
+  pub fn line<T: Borrow<Point>, U: Borrow<Point>>(&self, from: &T, to: &U) -> HashSet<Point> {
+    from.borrow()
+      .line(&self.image(from, to))
+      .iter()
+      .map(|other| self.normalize(other))
+      .collect()
+  }
+
+  /// Find reachable points of the same height within a range of a point
+  pub fn base_flood<T: Borrow<Point>, U: Borrow<Prism>>(
+    &self,
+    point: &T,
+    range: i32,
+    map: &HashMap<Point, U>,
+  ) -> HashSet<Point> {
+    self.generic_flood(point, range, Direction::planar(), map)
+  }
+
+  /// Find reachable points within a range of a point
+  pub fn flood<T: Borrow<Point>, U: Borrow<Prism>>(
+    &self,
+    point: &T,
+    range: i32,
+    map: &HashMap<Point, U>,
+  ) -> HashSet<Point> {
+    self.generic_flood(point, range, Direction::to_vec(), map)
+  }
+
+  /// Find reachable points by stepping in the provided directions
+  fn generic_flood<T: Borrow<Point>, U: Borrow<Prism>>(
+    &self,
+    point: &T,
+    range: i32,
+    directions: Vec<Direction>,
+    map: &HashMap<Point, U>,
+  ) -> HashSet<Point> {
+    let neighbors = |point: &Point| {
+      directions
+        .iter()
+        .filter(|direction| !self.has_wall(map, point, direction))
+        .map(|direction| self.travel(point, direction, 1))
+        .collect()
+    };
+
+    flood(self.normalize(point), range, neighbors)
+  }
+
+  /// Check for a wall on the side of a point, wrapping around to its neighbor
+  ///
+  /// The neighbor across an edge of the world is found before its wall is
+  /// checked, so the direction between the two points is always the step
+  /// that was taken rather than the one between their normalized positions.
+  fn has_wall<U: Borrow<Prism>>(
+    &self,
+    map: &HashMap<Point, U>,
+    point: &Point,
+    direction: &Direction,
+  ) -> bool {
+    let neighbor: Point = self.travel(point, direction, 1);
+
+    map.has_wall(point, direction) || map.has_wall(&neighbor, &direction.opposite())
+  }
+
+  /// Find the offset of a copy of the world
+  fn offset(&self, a: i32, b: i32) -> Point {
+    match *self {
+      Wrap::Rhombus(width, height) => Point(a * width, b * height, 0),
+      Wrap::Hexagon(radius) => Chunks(radius).center(&Point(a, b, 0)),
+    }
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+
+  const RHOMBUS: &Wrap = &Wrap::Rhombus(6, 4);
+  const HEXAGON: &Wrap = &Wrap::Hexagon(2);
+
+  #[test]
+  fn normalize_rhombus() {
+    assert!(Point(0, 3, 5) == RHOMBUS.normalize(&Point(6, -1, 5)));
+    assert!(Point(5, 0, 5) == RHOMBUS.normalize(&Point(-7, 8, 5)));
+    assert!(Point(1, 2, 5) == RHOMBUS.normalize(&Point(1, 2, 5)));
+  }
+
+  #[test]
+  #[should_panic]
+  fn normalize_empty_rhombus() {
+    Wrap::Rhombus(0, 4).normalize(&Point(1, 2, 5));
+  }
+
+  #[test]
+  fn normalize_hexagon() {
+    let origin: Point = Point(0, 0, 5);
+    let world: HashSet<Point> = origin.base_range(2);
+
+    assert!(Point(0, 0, 5) == HEXAGON.normalize(&Point(5, -2, 5)));
+    assert!(Point(-2, 2, 5) == HEXAGON.normalize(&Point(3, 0, 5)));
+
+    for point in &origin.base_range(12) {
+      let normalized: Point = HEXAGON.normalize(point);
+
+      assert!(world.contains(&normalized));
+      assert!(normalized == HEXAGON.normalize(&normalized));
+    }
+
+    let images: HashSet<Point> = Point(0, 0, 5)
+      .base_range(7)
+      .iter()
+      .map(|point| HEXAGON.normalize(point))
+      .collect();
+
+    assert!(images == world);
+  }
+
+  #[test]
+  fn travel() {
+    assert!(Point(0, 2, 5) == RHOMBUS.travel(&Point(5, 2, 5), &East, 1));
+    assert!(Point(-2, 2, 5) == HEXAGON.travel(&Point(2, 0, 5), &East, 1));
+    assert!(Point(5, 2, 6) == RHOMBUS.travel(&Point(5, 2, 5), &Up, 1));
+  }
+
+  #[test]
+  fn distance() {
+    assert!(1 == RHOMBUS.base_distance(&Point(5, 2, 5), &Point(0, 2, 5)));
+    assert!(1 == RHOMBUS.base_distance(&Point(0, 0, 5), &Point(1, 3, 5)));
+    assert!(3 == RHOMBUS.distance(&Point(0, 0, 5), &Point(1, 3, 7)));
+    assert!(1 == HEXAGON.base_distance(&Point(2, 0, 5), &Point(-2, 2, 5)));
+
+    for point in &Point(0, 0, 5).base_range(2) {
+      assert!(HEXAGON.base_distance(&Point(2, -1, 5), point) <= 2);
+    }
+  }
+
+  #[test]
+  fn range() {
+    let range: HashSet<Point> = RHOMBUS.base_range(&Point(5, 0, 5), 1);
+
+    assert!(range.len() == 7);
+    assert!(range.contains(&Point(0, 0, 5)));
+    assert!(range.contains(&Point(5, 3, 5)));
+    assert!(range.contains(&Point(0, 3, 5)));
+    assert!(RHOMBUS.range(&Point(5, 0, 5), 1).len() == 9);
+  }
+
+  #[test]
+  fn line() {
+    let line: HashSet<Point> = RHOMBUS.line(&Point(4, 1, 5), &Point(0, 1, 5));
+
+    assert!(line.len() == 3);
+    assert!(line.contains(&Point(5, 1, 5)));
+    assert!(line.contains(&Point(0, 1, 5)));
+  }
+
+  #[test]
+  fn flood() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert_walled_point(Prism(Point(5, 2, 5), 1, 0, 0, 0));
+
+    let flood: HashSet<Point> = RHOMBUS.base_flood(&Point(5, 2, 5), 1, &map);
+
+    assert!(flood.len() == 6);
+    assert!(!flood.contains(&Point(0, 2, 5)));
+    assert!(flood.contains(&Point(4, 3, 5)));
+
+    let unwalled: HashMap<Point, Prism> = HashMap::new();
+
+    assert!(RHOMBUS.base_flood(&Point(0, 0, 5), 10, &unwalled).len() == 24);
+    assert!(RHOMBUS.flood(&Point(0, 0, 5), 1, &unwalled).len() == 9);
+  }
+
+  #[test]
+  fn flood_seam_wall() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert_walled_point(Prism(Point(0, 2, 5), 0, 0, 1, 0));
+
+    let flood: HashSet<Point> = RHOMBUS.base_flood(&Point(5, 3, 5), 1, &map);
+
+    assert!(!flood.contains(&Point(0, 2, 5)));
+    assert!(flood.contains(&Point(0, 3, 5)));
+    assert!(!RHOMBUS.base_flood(&Point(0, 2, 5), 1, &map).contains(&Point(5, 3, 5)));
+  }
+}
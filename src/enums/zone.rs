@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use structs::Point;
+
+/// Enum describing how hostile zones of control affect movement
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Zone {
+  /// Entering a controlled point ends movement, although a unit which starts
+  /// in a controlled point may leave it
+  Stop,
+  /// Entering a controlled point costs extra on top of the step
+  Cost(i32),
+}
+
+impl Zone {
+
+  /// Find the cost of a single step, or `None` if the step is not allowed
+  ///
+  /// This is suitable as the cost function of `traits::range::GenericReach`.
+  pub fn cost(
+    &self,
+    start: &Point,
+    from: &Point,
+    to: &Point,
+    zones: &HashSet<Point>,
+  ) -> Option<i32> {
+    match *self {
+      Zone::Stop if from != start && zones.contains(from) => None,
+      Zone::Cost(extra) if zones.contains(to) => Some(1 + extra),
+      _ => Some(1),
+    }
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn cost() {
+    let start: Point = Point(0, 0, 0);
+    let controlled: Point = Point(1, 0, 0);
+    let beyond: Point = Point(2, 0, 0);
+    let zones: HashSet<Point> = vec![ start, controlled ].into_iter().collect();
+
+    assert!(Some(1) == Zone::Stop.cost(&start, &start, &controlled, &zones));
+    assert!(Zone::Stop.cost(&start, &controlled, &beyond, &zones).is_none());
+    assert!(Some(3) == Zone::Cost(2).cost(&start, &start, &controlled, &zones));
+    assert!(Some(1) == Zone::Cost(2).cost(&start, &controlled, &beyond, &zones));
+  }
+}
@@ -1 +1,3 @@
 pub mod line;
+pub mod path;
+pub mod search;
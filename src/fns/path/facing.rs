@@ -0,0 +1,61 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use enums::Direction;
+use structs::{Point, Prism};
+use traits::IsPointMap;
+use traits::travel::Travel;
+
+/// Find the states reachable in one move from a point with a facing.
+///
+/// A unit can step forward in the direction it faces for a cost of one, or
+/// turn one sixth of a circle either way for the turn cost, using the same
+/// sense of rotation as `Rotate::rotate`. Units which pay to turn stay at
+/// one height, so vertical facings have no neighbors.
+pub fn facing_neighbors<U: Borrow<Prism>>(
+  &(point, facing): &(Point, Direction),
+  turn_cost: i32,
+  map: &HashMap<Point, U>,
+) -> Vec<((Point, Direction), i32)> {
+  let mut neighbors: Vec<((Point, Direction), i32)> = Vec::new();
+
+  if facing.is_vertical() {
+    return neighbors;
+  }
+
+  let forward: Point = point.travel(&facing, 1);
+
+  if !map.has_wall_between(&point, &forward) {
+    neighbors.push(((forward, facing), 1));
+  }
+
+  neighbors.push(((point, facing.rotate(1)), turn_cost));
+  neighbors.push(((point, facing.rotate(-1)), turn_cost));
+
+  neighbors
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+
+  #[test]
+  fn facing_neighbors() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+    let point: Point = Point(1, 2, 5);
+
+    let neighbors = super::facing_neighbors(&(point, East), 2, &map);
+
+    assert!(neighbors == vec![
+      ((Point(2, 2, 5), East), 1),
+      ((point, Southeast), 2),
+      ((point, Northeast), 2),
+    ]);
+
+    map.insert_walled_point(Prism(point, 1, 0, 0, 0));
+
+    assert!(super::facing_neighbors(&(point, East), 2, &map).len() == 2);
+    assert!(super::facing_neighbors(&(point, Up), 2, &map).is_empty());
+  }
+}
@@ -0,0 +1,5 @@
+mod facing;
+mod smooth;
+
+pub use self::facing::facing_neighbors;
+pub use self::smooth::smooth;
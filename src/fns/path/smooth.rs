@@ -0,0 +1,102 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use structs::{Point, Prism};
+use traits::ray::Ray;
+
+/// Remove the waypoints of a path which can be skipped in a straight line.
+///
+/// Starting from the first point, each waypoint is kept only if the point
+/// after it can not be seen without crossing a wall. The first and last points
+/// are always kept, and no waypoint is repeated.
+pub fn smooth<T: Borrow<Point>, U: Borrow<Prism>>(
+  path: &[T],
+  map: &HashMap<Point, U>,
+) -> Vec<Point> {
+  let mut waypoints: Vec<Point> = Vec::new();
+
+  let (first, rest) = match path.split_first() {
+    Some(split) => split,
+    None => return waypoints,
+  };
+
+  let mut anchor: Point = *first.borrow();
+  let mut previous: Point = anchor;
+
+  waypoints.push(anchor);
+
+  for point in rest {
+    let point: Point = *point.borrow();
+
+    if previous != anchor && !anchor.ray(&point, map).contains(&point) {
+      waypoints.push(previous);
+      anchor = previous;
+    }
+
+    previous = point;
+  }
+
+  if previous != anchor {
+    waypoints.push(previous);
+  }
+
+  waypoints
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use traits::IsPointMap;
+  use traits::path::Base;
+
+  #[test]
+  fn smooth() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let path: Vec<Point> = vec![
+      Point(0, 0, 0),
+      Point(1, 0, 0),
+      Point(1, 1, 0),
+      Point(2, 1, 0),
+      Point(2, 2, 0),
+    ];
+
+    assert!(super::smooth(&path, &map) == vec![ Point(0, 0, 0), Point(2, 2, 0) ]);
+    assert!(super::smooth(&path[..1], &map) == vec![ Point(0, 0, 0) ]);
+    assert!(super::smooth(&Vec::<Point>::new(), &map).is_empty());
+  }
+
+  #[test]
+  fn smooth_walls() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    for r in -3..3 {
+      map.insert_walled_point(Prism(Point(0, r, 0), 1, 0, 0, 0));
+      map.insert_walled_point(Prism(Point(1, r, 0), 0, 0, 1, 0));
+    }
+
+    let start: Point = Point(0, 0, 0);
+    let goal: Point = Point(1, 0, 0);
+    let path: Vec<Point> = start.base_path(&goal, 20, &map).unwrap();
+    let waypoints: Vec<Point> = super::smooth(&path, &map);
+
+    assert!(waypoints.len() >= 3);
+    assert!(waypoints.len() < path.len());
+    assert!(waypoints[0] == start);
+    assert!(waypoints[waypoints.len() - 1] == goal);
+
+    for pair in waypoints.windows(2) {
+      assert!(pair[0].ray(&pair[1], &map).contains(&pair[1]));
+    }
+  }
+
+  #[test]
+  fn smooth_wall_between_steps() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert_walled_point(Prism(Point(0, 0, 0), 1, 0, 0, 0));
+
+    let path: Vec<Point> = vec![ Point(0, 0, 0), Point(1, 0, 0), Point(2, 0, 0) ];
+
+    assert!(super::smooth(&path, &map) == path);
+  }
+}
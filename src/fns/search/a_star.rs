@@ -0,0 +1,110 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// Find the cheapest path from a state to any goal state.
+///
+/// Neighbors are returned with the cost of moving to them, and the heuristic
+/// must never overestimate the cost left to reach a goal. The path includes
+/// both the start and the goal, and is returned with its total cost.
+pub fn a_star<S, G, N, H>(
+  start: S,
+  mut is_goal: G,
+  mut neighbors: N,
+  mut heuristic: H,
+) -> Option<(Vec<S>, i32)>
+  where S: Clone + Eq + Hash,
+        G: FnMut(&S) -> bool,
+        N: FnMut(&S) -> Vec<(S, i32)>,
+        H: FnMut(&S) -> i32,
+{
+  let mut open: BinaryHeap<Reverse<(i32, usize)>> = BinaryHeap::new();
+  let mut indices: HashMap<S, usize> = HashMap::new();
+  let mut states: Vec<S> = Vec::new();
+  let mut costs: Vec<i32> = Vec::new();
+  let mut previous: Vec<Option<usize>> = Vec::new();
+  let mut closed: Vec<bool> = Vec::new();
+
+  open.push(Reverse((heuristic(&start), 0)));
+  indices.insert(start.clone(), 0);
+  states.push(start);
+  costs.push(0);
+  previous.push(None);
+  closed.push(false);
+
+  while let Some(Reverse((_, index))) = open.pop() {
+    if closed[index] {
+      continue;
+    }
+
+    closed[index] = true;
+
+    if is_goal(&states[index]) {
+      let mut path: Vec<S> = Vec::new();
+      let mut current: Option<usize> = Some(index);
+
+      while let Some(step) = current {
+        path.push(states[step].clone());
+        current = previous[step];
+      }
+
+      path.reverse();
+
+      return Some((path, costs[index]));
+    }
+
+    for (state, cost) in neighbors(&states[index]) {
+      let total: i32 = costs[index] + cost;
+
+      let next: usize = match indices.get(&state) {
+        Some(&next) if closed[next] || total >= costs[next] => continue,
+        Some(&next) => next,
+        None => {
+          indices.insert(state.clone(), states.len());
+          states.push(state);
+          costs.push(total);
+          previous.push(None);
+          closed.push(false);
+
+          states.len() - 1
+        },
+      };
+
+      costs[next] = total;
+      previous[next] = Some(index);
+      open.push(Reverse((total + heuristic(&states[next]), next)));
+    }
+  }
+
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  #[test]
+  fn a_star() {
+    let neighbors = |&n: &i32| vec![ (n + 1, 1), (n * 2, 3) ];
+    let (path, cost) = super::a_star(1, |&n| n == 12, neighbors, |_| 0).unwrap();
+
+    assert!(path == vec![ 1, 2, 3, 6, 12 ]);
+    assert!(cost == 8);
+  }
+
+  #[test]
+  fn a_star_unreachable() {
+    let neighbors = |&n: &i32| if n < 5 { vec![ (n + 1, 1) ] } else { vec![] };
+
+    assert!(super::a_star(0, |&n| n == 10, neighbors, |_| 0).is_none());
+  }
+
+  #[test]
+  fn a_star_heuristic() {
+    let mut expanded: i32 = 0;
+    let neighbors = |&n: &i32| vec![ (n + 1, 1), (n - 1, 1) ];
+
+    let result = super::a_star(0, |&n| { expanded += 1; n == 20 }, neighbors, |&n| (20 - n).abs());
+
+    assert!(result.unwrap().1 == 20);
+    assert!(expanded == 21);
+  }
+}
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Follow the predecessors of a search back from a state to its start.
+///
+/// The path runs from the start to the state, and is `None` if the state
+/// was not reached.
+pub fn backtrack<S, V>(reached: &HashMap<S, (V, Option<S>)>, state: &S) -> Option<Vec<S>>
+  where S: Clone + Eq + Hash,
+{
+  let mut path: Vec<S> = Vec::new();
+  let mut current: Option<S> = Some(state.clone());
+
+  while let Some(step) = current {
+    current = reached.get(&step)?.1.clone();
+    path.push(step);
+  }
+
+  path.reverse();
+
+  Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn backtrack() {
+    let mut reached: HashMap<i32, (i32, Option<i32>)> = HashMap::new();
+
+    reached.insert(1, (0, None));
+    reached.insert(2, (1, Some(1)));
+    reached.insert(4, (2, Some(2)));
+
+    assert!(super::backtrack(&reached, &4) == Some(vec![ 1, 2, 4 ]));
+    assert!(super::backtrack(&reached, &1) == Some(vec![ 1 ]));
+    assert!(super::backtrack(&reached, &3).is_none());
+  }
+}
@@ -0,0 +1,57 @@
+use fns::search::a_star;
+use structs::Point;
+
+/// Find a shortest path between two points within a number of steps.
+///
+/// Neighbors are the points one step away which can be moved to, and the
+/// distance function must never overestimate the steps between two points.
+/// Points which could not lie on a path short enough are never searched. The
+/// path includes both ends, and is `None` if the goal can not be reached
+/// within the range.
+pub fn bounded_path<N, D>(
+  start: &Point,
+  goal: &Point,
+  range: i32,
+  mut neighbors: N,
+  distance_fn: D,
+) -> Option<Vec<Point>>
+  where N: FnMut(&Point) -> Vec<Point>,
+        D: Fn(&Point, &Point) -> i32,
+{
+  let steps = |point: &Point| {
+    neighbors(point)
+      .into_iter()
+      .filter(|neighbor| distance_fn(start, neighbor) + distance_fn(neighbor, goal) <= range)
+      .map(|neighbor| (neighbor, 1))
+      .collect()
+  };
+
+  let heuristic = |point: &Point| distance_fn(point, goal);
+
+  match a_star(*start, |point| point == goal, steps, heuristic) {
+    Some((path, cost)) if cost <= range => Some(path),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction;
+  use traits::distance::Base;
+  use traits::travel::Travel;
+
+  #[test]
+  fn bounded_path() {
+    let start: Point = Point(0, 0, 0);
+    let goal: Point = Point(3, 0, 0);
+    let neighbors = |point: &Point| {
+      Direction::planar().iter().map(|direction| point.travel(direction, 1)).collect()
+    };
+
+    let path: Vec<Point> = super::bounded_path(&start, &goal, 3, neighbors, Base::base_distance::<Point>).unwrap();
+
+    assert!(path.len() == 4);
+    assert!(super::bounded_path(&start, &goal, 2, neighbors, Base::base_distance::<Point>).is_none());
+  }
+}
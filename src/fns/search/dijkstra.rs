@@ -0,0 +1,70 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// Find the cheapest cost of reaching every state within a limit.
+///
+/// Neighbors are returned with the cost of moving to them. Each reached state
+/// is mapped to its cost and the state it was reached from, which is `None`
+/// only for the start.
+pub fn dijkstra<S, N>(
+  start: S,
+  limit: i32,
+  mut neighbors: N,
+) -> HashMap<S, (i32, Option<S>)>
+  where S: Clone + Eq + Hash,
+        N: FnMut(&S) -> Vec<(S, i32)>,
+{
+  let mut open: BinaryHeap<Reverse<(i32, usize)>> = BinaryHeap::new();
+  let mut reached: HashMap<S, (i32, Option<S>)> = HashMap::new();
+  let mut states: Vec<S> = Vec::new();
+
+  reached.insert(start.clone(), (0, None));
+  states.push(start);
+  open.push(Reverse((0, 0)));
+
+  while let Some(Reverse((cost, index))) = open.pop() {
+    let state: S = states[index].clone();
+
+    if reached[&state].0 < cost {
+      continue;
+    }
+
+    for (next, step) in neighbors(&state) {
+      let total: i32 = cost + step;
+
+      if total > limit {
+        continue;
+      }
+
+      match reached.get(&next) {
+        Some(&(known, _)) if known <= total => continue,
+        _ => (),
+      }
+
+      reached.insert(next.clone(), (total, Some(state.clone())));
+      states.push(next);
+      open.push(Reverse((total, states.len() - 1)));
+    }
+  }
+
+  reached
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn dijkstra() {
+    let neighbors = |&n: &i32| vec![ (n + 1, 1), (n * 2, 1), (n - 3, 5) ];
+    let reached: HashMap<i32, (i32, Option<i32>)> = super::dijkstra(1, 3, neighbors);
+
+    assert!(reached[&1] == (0, None));
+    assert!(reached[&2].0 == 1);
+    assert!(reached[&8] == (3, Some(4)));
+    assert!(reached[&3].0 == 2);
+    assert!(!reached.contains_key(&-2));
+    assert!(!reached.contains_key(&9));
+  }
+}
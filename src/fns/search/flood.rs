@@ -0,0 +1,45 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Find the states reachable from a state within a number of steps.
+///
+/// Neighbors are the states one step away which can be moved to. The result
+/// includes the start.
+pub fn flood<S, N>(start: S, range: i32, mut neighbors: N) -> HashSet<S>
+  where S: Clone + Eq + Hash,
+        N: FnMut(&S) -> Vec<S>,
+{
+  let mut visited: HashSet<S> = HashSet::new();
+  let mut fringes: Vec<S> = vec![ start.clone() ];
+
+  visited.insert(start);
+
+  for _ in 0 .. range {
+    let mut found: Vec<S> = Vec::new();
+
+    for state in &fringes {
+      for neighbor in neighbors(state) {
+        if visited.insert(neighbor.clone()) {
+          found.push(neighbor);
+        }
+      }
+    }
+
+    fringes = found;
+  }
+
+  visited
+}
+
+#[cfg(test)]
+mod tests {
+  #[test]
+  fn flood() {
+    let neighbors = |&n: &i32| if n < 5 { vec![ n - 1, n + 1 ] } else { vec![] };
+    let result = super::flood(0, 3, neighbors);
+
+    assert!(result.len() == 7);
+    assert!(super::flood(4, 3, neighbors).len() == 5);
+    assert!(super::flood(0, 0, neighbors).len() == 1);
+  }
+}
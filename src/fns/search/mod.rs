@@ -0,0 +1,11 @@
+mod a_star;
+mod backtrack;
+mod bounded_path;
+mod dijkstra;
+mod flood;
+
+pub use self::a_star::a_star;
+pub use self::backtrack::backtrack;
+pub use self::bounded_path::bounded_path;
+pub use self::dijkstra::dijkstra;
+pub use self::flood::flood;
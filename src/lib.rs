@@ -6,5 +6,8 @@ pub mod traits;
 mod enums;
 mod structs;
 
-pub use enums::Direction;
-pub use structs::{PixelPoint, Point, Prism};
+pub use enums::{Axis, Corner, Diagonal, Direction, DirectionError, Wrap, Zone};
+pub use structs::{BoundingBox, Chunks, DirectionSet, Edge, Footprint, HexSet, Hierarchy, PixelPoint, Point, Polygon, Prism, Reservations, Transform, Vertex, WallMap};
+pub use structs::range::Iterator as RangeIterator;
+pub use structs::ring::{Iterator as RingIterator, Shell};
+pub use structs::spiral::Iterator as SpiralIterator;
@@ -0,0 +1,207 @@
+use std::borrow::Borrow;
+use std::cmp::{max, min};
+use std::vec;
+
+use structs::Point;
+
+/// A box holding every point between a minimum and maximum on each axis
+///
+/// Each axis is stored as an inclusive pair of its minimum and maximum. The
+/// box is bounded on all three planar axes, so it is a hexagon when seen from
+/// above, with some sides possibly of zero length.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BoundingBox {
+  pub q: (i32, i32),
+  pub r: (i32, i32),
+  pub s: (i32, i32),
+  pub t: (i32, i32),
+}
+
+impl BoundingBox {
+
+  /// Create the smallest box holding every provided point
+  ///
+  /// Returns `None` when there are no points.
+  pub fn from_points<T, U>(points: U) -> Option<BoundingBox>
+    where T: Borrow<Point>, U: IntoIterator<Item = T>
+  {
+    let mut points = points.into_iter();
+    let first: Point = *points.next()?.borrow();
+
+    let mut bounds: BoundingBox = BoundingBox {
+      q: (first.0, first.0),
+      r: (first.1, first.1),
+      s: (first.s(), first.s()),
+      t: (first.2, first.2),
+    };
+
+    for point in points {
+      let point: &Point = point.borrow();
+
+      bounds.q = (min(bounds.q.0, point.0), max(bounds.q.1, point.0));
+      bounds.r = (min(bounds.r.0, point.1), max(bounds.r.1, point.1));
+      bounds.s = (min(bounds.s.0, point.s()), max(bounds.s.1, point.s()));
+      bounds.t = (min(bounds.t.0, point.2), max(bounds.t.1, point.2));
+    }
+
+    Some(bounds)
+  }
+
+  /// Return whether the box contains a point
+  pub fn contains<T: Borrow<Point>>(&self, point: &T) -> bool {
+    let point: &Point = point.borrow();
+
+    Self::within(&self.q, point.0) &&
+    Self::within(&self.r, point.1) &&
+    Self::within(&self.s, point.s()) &&
+    Self::within(&self.t, point.2)
+  }
+
+  /// Return whether the box contains no points
+  ///
+  /// A box can be empty even when every minimum is at most its maximum,
+  /// because the three planar coordinates of a point always sum to zero.
+  pub fn is_empty(&self) -> bool {
+    self.q.0 > self.q.1 ||
+    self.r.0 > self.r.1 ||
+    self.s.0 > self.s.1 ||
+    self.t.0 > self.t.1 ||
+    self.s.0 > -(self.q.0 + self.r.0) ||
+    self.s.1 < -(self.q.1 + self.r.1)
+  }
+
+  /// Find the box of points in both boxes
+  ///
+  /// Returns `None` when the boxes share no points.
+  pub fn intersection(&self, other: &BoundingBox) -> Option<BoundingBox> {
+    let bounds: BoundingBox = BoundingBox {
+      q: (max(self.q.0, other.q.0), min(self.q.1, other.q.1)),
+      r: (max(self.r.0, other.r.0), min(self.r.1, other.r.1)),
+      s: (max(self.s.0, other.s.0), min(self.s.1, other.s.1)),
+      t: (max(self.t.0, other.t.0), min(self.t.1, other.t.1)),
+    };
+
+    match bounds.is_empty() {
+      true => None,
+      false => Some(bounds),
+    }
+  }
+
+  /// Grow the box by a range on the planar axes and a height on the vertical
+  pub fn expand(&self, range: i32, height: i32) -> BoundingBox {
+    BoundingBox {
+      q: (self.q.0 - range, self.q.1 + range),
+      r: (self.r.0 - range, self.r.1 + range),
+      s: (self.s.0 - range, self.s.1 + range),
+      t: (self.t.0 - height, self.t.1 + height),
+    }
+  }
+
+  /// Get a vector of the points in the box, ordered by t, then q, then r
+  pub fn points(&self) -> Vec<Point> {
+    let mut points: Vec<Point> = Vec::new();
+
+    for t in self.t.0 ..= self.t.1 {
+      for q in self.q.0 ..= self.q.1 {
+        let first: i32 = max(self.r.0, -q - self.s.1);
+        let last: i32 = min(self.r.1, -q - self.s.0);
+
+        for r in first ..= last {
+          points.push(Point(q, r, t));
+        }
+      }
+    }
+
+    points
+  }
+
+  /// Return whether a value is between an inclusive minimum and maximum
+  fn within(&(low, high): &(i32, i32), value: i32) -> bool {
+    low <= value && value <= high
+  }
+
+}
+
+/// Iterate over the points in the box
+impl IntoIterator for BoundingBox {
+  type Item = Point;
+  type IntoIter = vec::IntoIter<Point>;
+
+  fn into_iter(self) -> vec::IntoIter<Point> {
+    self.points().into_iter()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashSet;
+  use traits::range::{Base, Cylinder};
+
+  const CENTER: &Point = &Point(1, 2, 5);
+
+  #[test]
+  fn from_points() {
+    let bounds: BoundingBox = BoundingBox::from_points(CENTER.cylinder(2, 1)).unwrap();
+
+    assert!((-1, 3) == bounds.q);
+    assert!(( 0, 4) == bounds.r);
+    assert!((-5, -1) == bounds.s);
+    assert!(( 4, 6) == bounds.t);
+    assert!(BoundingBox::from_points(Vec::<Point>::new()).is_none());
+  }
+
+  #[test]
+  fn points() {
+    let set: HashSet<Point> = CENTER.cylinder(2, 1);
+    let bounds: BoundingBox = BoundingBox::from_points(&set).unwrap();
+    let points: Vec<Point> = bounds.points();
+
+    assert!(points.len() == set.len());
+    assert!(points.iter().all(|point| set.contains(point)));
+    assert!(points[0] == Point(-1, 2, 4));
+  }
+
+  #[test]
+  fn contains() {
+    let bounds: BoundingBox = BoundingBox::from_points(CENTER.base_range(1)).unwrap();
+
+    assert!(bounds.contains(CENTER));
+    assert!(bounds.contains(&Point(2, 1, 5)));
+    assert!(!bounds.contains(&Point(2, 2, 4)));
+    assert!(!bounds.contains(&Point(2, 0, 5)));
+  }
+
+  #[test]
+  fn intersection() {
+    let a: BoundingBox = BoundingBox::from_points(CENTER.base_range(2)).unwrap();
+    let b: BoundingBox = BoundingBox::from_points(Point(4, 2, 5).base_range(2)).unwrap();
+    let c: BoundingBox = BoundingBox::from_points(Point(9, 2, 5).base_range(2)).unwrap();
+
+    let both: HashSet<Point> = a.intersection(&b).unwrap().into_iter().collect();
+    let expected: HashSet<Point> = CENTER.base_range(2)
+      .intersection(&Point(4, 2, 5).base_range(2))
+      .cloned()
+      .collect();
+
+    assert!(both == expected);
+    assert!(a.intersection(&c).is_none());
+  }
+
+  #[test]
+  fn is_empty() {
+    let bounds: BoundingBox = BoundingBox { q: (0, 0), r: (0, 0), s: (1, 1), t: (0, 0) };
+
+    assert!(bounds.is_empty());
+    assert!(bounds.points().is_empty());
+    assert!(!bounds.expand(1, 0).is_empty());
+  }
+
+  #[test]
+  fn expand() {
+    let bounds: BoundingBox = BoundingBox::from_points(vec![ *CENTER ]).unwrap();
+    let expanded: HashSet<Point> = bounds.expand(2, 1).into_iter().collect();
+
+    assert!(expanded == CENTER.cylinder(2, 1));
+  }
+}
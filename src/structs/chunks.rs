@@ -0,0 +1,162 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use structs::Point;
+use traits::range::Base as BaseRange;
+
+/// A division of the plane into hexagonal chunks of a radius
+///
+/// Chunks are addressed by coarse points, which form a hexagon grid of their
+/// own: the chunk one step east of another is its coarse neighbor to the
+/// east, and so on around, although the coarse grid is slightly rotated
+/// relative to the fine grid. Heights are not divided, so the height of a
+/// coarse point is the height of the fine points in its chunk. The radius
+/// must not be negative.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Chunks(pub i32);
+
+impl Chunks {
+
+  /// Count the points in each chunk
+  pub fn size(&self) -> i32 {
+    let &Chunks(radius) = self;
+
+    3 * radius * radius + 3 * radius + 1
+  }
+
+  /// Find the chunk containing a point
+  pub fn chunk<T: Borrow<Point>>(&self, point: &T) -> Point {
+    self.split(point).0
+  }
+
+  /// Find the offset of a point from the center of its chunk
+  ///
+  /// The offset has no height, since the chunk keeps the height of the point.
+  pub fn local<T: Borrow<Point>>(&self, point: &T) -> Point {
+    self.split(point).1
+  }
+
+  /// Find the chunk containing a point and the offset from its center
+  ///
+  /// Panics if the radius is negative.
+  pub fn split<T: Borrow<Point>>(&self, point: &T) -> (Point, Point) {
+    let &Chunks(radius) = self;
+    let &Point(q, r, t) = point.borrow();
+
+    assert!(radius >= 0, "chunk radius must not be negative");
+
+    let (radius, q, r): (i64, i64, i64) = (radius as i64, q as i64, r as i64);
+    let size: i64 = 3 * radius * radius + 3 * radius + 1;
+
+    let a: i64 = (2 * ((radius + 1) * q - radius * r) + size).div_euclid(2 * size);
+    let b: i64 = (2 * (radius * q + (2 * radius + 1) * r) + size).div_euclid(2 * size);
+
+    for a in a - 1 ..= a + 1 {
+      for b in b - 1 ..= b + 1 {
+        let lq: i64 = q - (a * (2 * radius + 1) + b * radius);
+        let lr: i64 = r - (-a * radius + b * (radius + 1));
+
+        if lq.abs().max(lr.abs()).max((lq + lr).abs()) <= radius {
+          return (Point(a as i32, b as i32, t), Point(lq as i32, lr as i32, 0));
+        }
+      }
+    }
+
+    unreachable!()
+  }
+
+  /// Find the point at an offset from the center of a chunk
+  pub fn join<T: Borrow<Point>, U: Borrow<Point>>(&self, chunk: &T, local: &U) -> Point {
+    &self.center(chunk) + local.borrow()
+  }
+
+  /// Find the point at the center of a chunk
+  pub fn center<T: Borrow<Point>>(&self, chunk: &T) -> Point {
+    let &Chunks(radius) = self;
+    let &Point(a, b, t) = chunk.borrow();
+
+    Point(
+      a * (2 * radius + 1) + b * radius,
+      -a * radius + b * (radius + 1),
+      t,
+    )
+  }
+
+  /// Find the points in a chunk
+  pub fn points<T: Borrow<Point>>(&self, chunk: &T) -> HashSet<Point> {
+    let &Chunks(radius) = self;
+
+    self.center(chunk).base_range(radius)
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use traits::distance::Base as BaseDistance;
+  use enums::Direction;
+  use traits::travel::Travel;
+
+  const CHUNKS: &Chunks = &Chunks(2);
+
+  #[test]
+  fn size() {
+    assert!(CHUNKS.size() == 19);
+    assert!(Chunks(0).size() == 1);
+    assert!(CHUNKS.points(&Point(1, -1, 5)).len() == 19);
+  }
+
+  #[test]
+  fn center() {
+    assert!(Point(5, -2, 5) == CHUNKS.center(&Point(1, 0, 5)));
+    assert!(Point(2, 3, 5) == CHUNKS.center(&Point(0, 1, 5)));
+    assert!(Point(0, 0, 5) == CHUNKS.chunk(&Point(0, 0, 5)));
+  }
+
+  #[test]
+  fn split() {
+    assert!((Point(1, 0, 5), Point(-2, 2, 0)) == CHUNKS.split(&Point(3, 0, 5)));
+    assert!(Point(1, 0, 5) == CHUNKS.chunk(&Point(3, 0, 5)));
+    assert!(Point(-2, 2, 0) == CHUNKS.local(&Point(3, 0, 5)));
+  }
+
+  #[test]
+  fn split_large() {
+    for &(chunks, q) in &[ (Chunks(0), (1 << 30) + 37), (Chunks(2), (1 << 29) + 37), (Chunks(7), -(1 << 29)) ] {
+      let point: Point = Point(q, -q / 2, 5);
+      let (chunk, local) = chunks.split(&point);
+
+      assert!(local.base_distance(&Point(0, 0, 0)) <= chunks.0);
+      assert!(chunks.join(&chunk, &local) == point);
+    }
+  }
+
+  #[test]
+  #[should_panic]
+  fn split_negative() {
+    Chunks(-1).split(&Point(0, 0, 0));
+  }
+
+  #[test]
+  fn join() {
+    for point in &Point(0, 0, 5).base_range(12) {
+      let (chunk, local) = CHUNKS.split(point);
+
+      assert!(local.base_distance(&Point(0, 0, 5)) <= 2);
+      assert!(CHUNKS.points(&chunk).contains(point));
+      assert!(*point == CHUNKS.join(&chunk, &local));
+    }
+  }
+
+  #[test]
+  fn neighbors() {
+    let center: Point = CHUNKS.center(&Point(0, 0, 5));
+
+    for direction in &Direction::planar() {
+      let neighbor: Point = CHUNKS.center(&Point(0, 0, 5).travel(direction, 1));
+
+      assert!(center.base_distance(&neighbor) == 5);
+    }
+  }
+}
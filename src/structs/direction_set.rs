@@ -0,0 +1,228 @@
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitOr, Not, Sub};
+use std::vec;
+
+use enums::Direction;
+
+/// A compact set of directions
+///
+/// Each direction is stored as one bit at the position of its index in
+/// `Direction::to_vec`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DirectionSet(pub u8);
+
+impl DirectionSet {
+
+  /// Create a set without any directions
+  pub fn new() -> DirectionSet {
+    DirectionSet(0)
+  }
+
+  /// Create a set with every direction
+  pub fn all() -> DirectionSet {
+    DirectionSet(0xff)
+  }
+
+  /// Return whether the set contains a direction
+  pub fn contains(&self, direction: &Direction) -> bool {
+    self.0 & Self::bit(direction) != 0
+  }
+
+  /// Add a direction, returning whether it was not already present
+  pub fn insert(&mut self, direction: &Direction) -> bool {
+    let absent = !self.contains(direction);
+
+    self.0 |= Self::bit(direction);
+
+    absent
+  }
+
+  /// Remove a direction, returning whether it was present
+  pub fn remove(&mut self, direction: &Direction) -> bool {
+    let present = self.contains(direction);
+
+    self.0 &= !Self::bit(direction);
+
+    present
+  }
+
+  /// Count the directions in the set
+  pub fn len(&self) -> usize {
+    self.0.count_ones() as usize
+  }
+
+  /// Return whether the set has no directions
+  pub fn is_empty(&self) -> bool {
+    self.0 == 0
+  }
+
+  /// Find the directions in either set
+  pub fn union(&self, other: &DirectionSet) -> DirectionSet {
+    DirectionSet(self.0 | other.0)
+  }
+
+  /// Find the directions in both sets
+  pub fn intersection(&self, other: &DirectionSet) -> DirectionSet {
+    DirectionSet(self.0 & other.0)
+  }
+
+  /// Find the directions in this set but not the other
+  pub fn difference(&self, other: &DirectionSet) -> DirectionSet {
+    DirectionSet(self.0 & !other.0)
+  }
+
+  /// Find the directions not in this set
+  pub fn complement(&self) -> DirectionSet {
+    DirectionSet(!self.0)
+  }
+
+  /// Rotate every direction in the set
+  ///
+  /// Positive rotations are clockwise. Up and down are not changed by
+  /// rotation.
+  pub fn rotate(&self, times: i32) -> DirectionSet {
+    self.to_vec().iter().map(|direction| direction.rotate(times)).collect()
+  }
+
+  /// Get a vector of the directions in the order of `Direction::to_vec`
+  pub fn to_vec(&self) -> Vec<Direction> {
+    Direction::to_vec()
+      .into_iter()
+      .filter(|direction| self.contains(direction))
+      .collect()
+  }
+
+  /// Return the bit used to store a direction
+  fn bit(direction: &Direction) -> u8 {
+    1 << direction.index()
+  }
+
+}
+
+/// Create a set with a single direction
+impl From<Direction> for DirectionSet {
+  fn from(direction: Direction) -> DirectionSet {
+    DirectionSet(Self::bit(&direction))
+  }
+}
+
+/// Collect directions into a set
+impl FromIterator<Direction> for DirectionSet {
+  fn from_iter<T: IntoIterator<Item = Direction>>(iter: T) -> DirectionSet {
+    let mut set: DirectionSet = DirectionSet::new();
+
+    for direction in iter {
+      set.insert(&direction);
+    }
+
+    set
+  }
+}
+
+/// Iterate over the directions in the set
+impl IntoIterator for DirectionSet {
+  type Item = Direction;
+  type IntoIter = vec::IntoIter<Direction>;
+
+  fn into_iter(self) -> vec::IntoIter<Direction> {
+    self.to_vec().into_iter()
+  }
+}
+
+/// Find the union of two sets
+impl BitOr<&DirectionSet> for &DirectionSet {
+  type Output = DirectionSet;
+
+  fn bitor(self, other: &DirectionSet) -> DirectionSet {
+    self.union(other)
+  }
+}
+
+/// Find the intersection of two sets
+impl BitAnd<&DirectionSet> for &DirectionSet {
+  type Output = DirectionSet;
+
+  fn bitand(self, other: &DirectionSet) -> DirectionSet {
+    self.intersection(other)
+  }
+}
+
+/// Find the difference of two sets
+impl Sub<&DirectionSet> for &DirectionSet {
+  type Output = DirectionSet;
+
+  fn sub(self, other: &DirectionSet) -> DirectionSet {
+    self.difference(other)
+  }
+}
+
+/// Find the complement of a set
+impl Not for &DirectionSet {
+  type Output = DirectionSet;
+
+  fn not(self) -> DirectionSet {
+    self.complement()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+
+  #[test]
+  fn insert() {
+    let mut set: DirectionSet = DirectionSet::new();
+
+    assert!(set.insert(&East));
+    assert!(!set.insert(&East));
+    assert!(set.insert(&Down));
+    assert!(set.contains(&East));
+    assert!(set.contains(&Down));
+    assert!(!set.contains(&West));
+    assert!(set.len() == 2);
+  }
+
+  #[test]
+  fn remove() {
+    let mut set: DirectionSet = DirectionSet::all();
+
+    assert!(set.remove(&Up));
+    assert!(!set.remove(&Up));
+    assert!(!set.contains(&Up));
+    assert!(set.len() == 7);
+  }
+
+  #[test]
+  fn is_empty() {
+    assert!(DirectionSet::new().is_empty());
+    assert!(!DirectionSet::from(West).is_empty());
+  }
+
+  #[test]
+  fn set_algebra() {
+    let a: DirectionSet = vec![ East, Southeast, Up ].into_iter().collect();
+    let b: DirectionSet = vec![ Southeast, West ].into_iter().collect();
+
+    assert!((&a | &b).to_vec() == vec![ East, Southeast, West, Up ]);
+    assert!((&a & &b).to_vec() == vec![ Southeast ]);
+    assert!((&a - &b).to_vec() == vec![ East, Up ]);
+    assert!((!&a).to_vec() == vec![ Southwest, West, Northwest, Northeast, Down ]);
+  }
+
+  #[test]
+  fn rotate() {
+    let set: DirectionSet = vec![ East, Northeast, Down ].into_iter().collect();
+
+    assert!(set.rotate(1).to_vec() == vec![ East, Southeast, Down ]);
+    assert!(set.rotate(-6) == set);
+  }
+
+  #[test]
+  fn into_iter() {
+    let set: DirectionSet = vec![ Up, West ].into_iter().collect();
+    let vec: Vec<Direction> = set.into_iter().collect();
+
+    assert!(vec == vec![ West, Up ]);
+  }
+}
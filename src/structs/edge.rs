@@ -0,0 +1,66 @@
+use std::borrow::Borrow;
+
+use enums::Direction;
+use structs::Point;
+use traits::travel::Travel;
+
+/// An edge is the side shared by two neighboring points
+///
+/// Edges are stored with the same four directions that a prism uses for its
+/// walls, so the edge described from either side is the same edge.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Edge(pub Point, pub Direction);
+
+impl Edge {
+
+  /// Return the two points on either side of the edge
+  pub fn points(&self) -> (Point, Point) {
+    let &Edge(point, direction) = self;
+
+    (point, point.travel(&direction, 1))
+  }
+
+}
+
+impl <'a, 'b, T> From<(&'a T, &'b Direction)> for Edge where T: Borrow<Point> {
+
+  /// Get the edge on the side of a point in the provided direction
+  fn from((point, direction): (&'a T, &'b Direction)) -> Edge {
+    let point: &Point = point.borrow();
+
+    match direction {
+      &Direction::East      |
+      &Direction::Southeast |
+      &Direction::Southwest |
+      &Direction::Down      => Edge(*point, *direction),
+      _ => Edge(point.travel(direction, 1), direction.opposite()),
+    }
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn points() {
+    let (p0, p1) = Edge(Point(1, 2, 5), Direction::Southwest).points();
+
+    assert!(Point(1, 2, 5) == p0);
+    assert!(Point(0, 3, 5) == p1);
+  }
+
+  #[test]
+  fn from() {
+    let point: Point = Point(1, 2, 5);
+
+    let east: Edge = Edge::from((&point, &Direction::East));
+    let west: Edge = Edge::from((&point, &Direction::West));
+    let up:   Edge = Edge::from((&point, &Direction::Up));
+
+    assert!(Edge(Point(1, 2, 5), Direction::East) == east);
+    assert!(Edge(Point(0, 2, 5), Direction::East) == west);
+    assert!(Edge(Point(1, 2, 6), Direction::Down) == up);
+  }
+}
@@ -0,0 +1,296 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+use enums::Direction;
+use fns::search::{bounded_path, flood};
+use structs::{Point, Prism};
+use traits::IsPointMap;
+use traits::distance::{Base as BaseDistance, Distance};
+use traits::rotate::Rotate;
+use traits::travel::Travel;
+
+/// The shape of a unit which occupies more than one point
+///
+/// The shape is stored as offsets from an anchor point for a unit facing
+/// east, and is rotated with the unit for other planar facings. Offsets may
+/// have heights, so a unit can also be several points tall. A unit fits where
+/// no wall separates two of its neighboring points, and can move in a
+/// direction when none of its points would cross a wall doing so.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Footprint(pub Vec<Point>);
+
+impl Footprint {
+
+  /// Find the points occupied by a unit at an anchor with a facing
+  ///
+  /// Up and down facings do not rotate the shape.
+  pub fn points<T: Borrow<Point>>(&self, anchor: &T, facing: &Direction) -> Vec<Point> {
+    let origin: Point = Point(0, 0, 0);
+    let times: i32 = match facing.is_vertical() {
+      true => 0,
+      false => facing.index() as i32,
+    };
+
+    self.0
+      .iter()
+      .map(|offset| anchor.borrow() + &offset.rotate(&origin, times))
+      .collect()
+  }
+
+  /// Return whether a unit fits at an anchor with a facing
+  pub fn fits<T: Borrow<Point>, U: Borrow<Prism>>(
+    &self,
+    anchor: &T,
+    facing: &Direction,
+    map: &HashMap<Point, U>,
+  ) -> bool {
+    let points: Vec<Point> = self.points(anchor, facing);
+
+    points.iter().all(|point| {
+      Direction::to_vec().iter().all(|direction| {
+        let neighbor: Point = point.travel(direction, 1);
+
+        !points.contains(&neighbor) || !map.has_wall_between(point, &neighbor)
+      })
+    })
+  }
+
+  /// Return whether a unit can move one step in a direction
+  pub fn can_move<T: Borrow<Point>, U: Borrow<Prism>>(
+    &self,
+    anchor: &T,
+    facing: &Direction,
+    direction: &Direction,
+    map: &HashMap<Point, U>,
+  ) -> bool {
+    let moved: Point = anchor.borrow().travel(direction, 1);
+
+    self.points(anchor, facing)
+      .iter()
+      .all(|point| !map.has_wall_between(point, &point.travel(direction, 1))) &&
+    self.fits(&moved, facing, map)
+  }
+
+  /// Find the anchors of the same height a unit can reach within a range
+  ///
+  /// The result is empty if the unit does not fit at the anchor.
+  pub fn base_flood<T: Borrow<Point>, U: Borrow<Prism>>(
+    &self,
+    anchor: &T,
+    facing: &Direction,
+    range: i32,
+    map: &HashMap<Point, U>,
+  ) -> HashSet<Point> {
+    self.generic_flood(anchor, facing, range, Direction::planar(), map)
+  }
+
+  /// Find the anchors a unit can reach within a range
+  ///
+  /// The result is empty if the unit does not fit at the anchor.
+  pub fn flood<T: Borrow<Point>, U: Borrow<Prism>>(
+    &self,
+    anchor: &T,
+    facing: &Direction,
+    range: i32,
+    map: &HashMap<Point, U>,
+  ) -> HashSet<Point> {
+    self.generic_flood(anchor, facing, range, Direction::to_vec(), map)
+  }
+
+  /// Find a shortest path of anchors of the same height within a range
+  ///
+  /// The path includes both ends, and is `None` if the unit does not fit at
+  /// the anchor or can not reach the goal within the range.
+  pub fn base_path<T: Borrow<Point>, U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    anchor: &T,
+    goal: &U,
+    facing: &Direction,
+    range: i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>> {
+    self.generic_path(anchor, goal, facing, range, false, map)
+  }
+
+  /// Find a shortest path of anchors within a range
+  ///
+  /// The path includes both ends, and is `None` if the unit does not fit at
+  /// the anchor or can not reach the goal within the range.
+  pub fn path<T: Borrow<Point>, U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    anchor: &T,
+    goal: &U,
+    facing: &Direction,
+    range: i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>> {
+    self.generic_path(anchor, goal, facing, range, true, map)
+  }
+
+  /// Find the anchors reachable by moving in the provided directions
+  fn generic_flood<T: Borrow<Point>, U: Borrow<Prism>>(
+    &self,
+    anchor: &T,
+    facing: &Direction,
+    range: i32,
+    directions: Vec<Direction>,
+    map: &HashMap<Point, U>,
+  ) -> HashSet<Point> {
+    if !self.fits(anchor, facing, map) {
+      return HashSet::new();
+    }
+
+    let neighbors = |point: &Point| {
+      directions
+        .iter()
+        .filter(|direction| self.can_move(point, facing, direction, map))
+        .map(|direction| point.travel(direction, 1))
+        .collect()
+    };
+
+    flood(*anchor.borrow(), range, neighbors)
+  }
+
+  /// Find a shortest path of anchors, moving vertically if requested
+  fn generic_path<T: Borrow<Point>, U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    anchor: &T,
+    goal: &U,
+    facing: &Direction,
+    range: i32,
+    vertical: bool,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>> {
+    if !self.fits(anchor, facing, map) {
+      return None;
+    }
+
+    let directions: Vec<Direction> = match vertical {
+      true => Direction::to_vec(),
+      false => Direction::planar(),
+    };
+
+    let distance_fn: fn(&Point, &Point) -> i32 = match vertical {
+      true => Distance::distance::<Point>,
+      false => BaseDistance::base_distance::<Point>,
+    };
+
+    let neighbors = |point: &Point| {
+      directions
+        .iter()
+        .filter(|direction| self.can_move(point, facing, direction, map))
+        .map(|direction| point.travel(direction, 1))
+        .collect()
+    };
+
+    bounded_path(anchor.borrow(), goal.borrow(), range, neighbors, distance_fn)
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+  use traits::path::Base;
+
+  fn pair() -> Footprint {
+    Footprint(vec![ Point(0, 0, 0), Point(1, 0, 0) ])
+  }
+
+  fn tall() -> Footprint {
+    Footprint(vec![ Point(0, 0, 0), Point(0, 0, 1) ])
+  }
+
+  fn gap() -> HashMap<Point, Prism> {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    for q in -4..5 {
+      if q != 0 {
+        map.insert_walled_point(Prism(Point(q, 0, 0), 0, 1, 1, 0));
+      }
+    }
+
+    map
+  }
+
+  #[test]
+  fn points() {
+    let footprint: Footprint = pair();
+
+    assert!(footprint.points(&Point(1, 2, 5), &East) == vec![ Point(1, 2, 5), Point(2, 2, 5) ]);
+    assert!(footprint.points(&Point(1, 2, 5), &Southeast) == vec![ Point(1, 2, 5), Point(1, 3, 5) ]);
+    assert!(footprint.points(&Point(1, 2, 5), &Up) == footprint.points(&Point(1, 2, 5), &East));
+  }
+
+  #[test]
+  fn fits() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert_walled_point(Prism(Point(0, 0, 0), 1, 0, 0, 0));
+
+    assert!(!pair().fits(&Point(0, 0, 0), &East, &map));
+    assert!(pair().fits(&Point(0, 0, 0), &West, &map));
+    assert!(tall().fits(&Point(0, 0, 0), &East, &map));
+  }
+
+  #[test]
+  fn can_move() {
+    let map: HashMap<Point, Prism> = gap();
+
+    assert!(Footprint(vec![ Point(0, 0, 0) ]).can_move(&Point(0, 0, 0), &East, &Southeast, &map));
+    assert!(!pair().can_move(&Point(0, 0, 0), &East, &Southeast, &map));
+    assert!(!pair().can_move(&Point(-1, 0, 0), &East, &Southeast, &map));
+    assert!(pair().can_move(&Point(0, 0, 0), &Southeast, &Southeast, &map));
+  }
+
+  #[test]
+  fn can_move_tall() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert_walled_point(Prism(Point(0, 0, 1), 1, 0, 0, 0));
+
+    assert!(!tall().can_move(&Point(0, 0, 0), &East, &East, &map));
+    assert!(tall().can_move(&Point(0, 0, 0), &East, &West, &map));
+  }
+
+  #[test]
+  fn flood() {
+    let map: HashMap<Point, Prism> = gap();
+    let single: HashSet<Point> = Footprint(vec![ Point(0, 0, 0) ]).base_flood(&Point(0, -1, 0), &East, 2, &map);
+    let wide: HashSet<Point> = pair().base_flood(&Point(0, -1, 0), &East, 2, &map);
+
+    assert!(single.contains(&Point(0, 1, 0)));
+    assert!(!wide.contains(&Point(0, 1, 0)));
+    assert!(!wide.iter().any(|point| point.1 > 0));
+    assert!(pair().flood(&Point(0, -1, 0), &East, 1, &map).len() == 9);
+  }
+
+  #[test]
+  fn path() {
+    let map: HashMap<Point, Prism> = gap();
+    let start: Point = Point(0, -2, 0);
+    let goal: Point = Point(0, 2, 0);
+
+    assert!(start.base_path(&goal, 10, &map).unwrap().len() == 5);
+    assert!(pair().base_path(&start, &goal, &East, 10, &map).is_none());
+    assert!(pair().base_path(&start, &goal, &Southeast, 10, &map).unwrap().len() == 5);
+    assert!(pair().path(&start, &goal, &East, 10, &map).unwrap().iter().any(|point| point.2 > 0));
+  }
+
+  #[test]
+  fn start_does_not_fit() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+    let start: Point = Point(0, 0, 0);
+    let goal: Point = Point(0, 2, 0);
+
+    map.insert_walled_point(Prism(start, 1, 0, 0, 0));
+
+    assert!(pair().base_flood(&start, &East, 2, &map).is_empty());
+    assert!(pair().flood(&start, &East, 2, &map).is_empty());
+    assert!(pair().base_path(&start, &goal, &East, 10, &map).is_none());
+    assert!(pair().path(&start, &goal, &East, 10, &map).is_none());
+    assert!(!pair().base_flood(&start, &West, 2, &map).is_empty());
+    assert!(pair().base_path(&start, &goal, &West, 10, &map).is_some());
+  }
+}
@@ -0,0 +1,350 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::collections::hash_set;
+use std::iter::FromIterator;
+
+use enums::{Corner, Direction};
+use enums::Direction::*;
+use structs::{PixelPoint, Point, Polygon, Vertex};
+use traits::range::Base as BaseRange;
+use traits::ring::Base as BaseRing;
+use traits::travel::Travel;
+
+/// A region of points
+///
+/// Growing, shrinking, boundaries and components only consider neighbors at
+/// the same height, so each height of a region behaves as its own plane.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HexSet(pub HashSet<Point>);
+
+impl HexSet {
+
+  /// Create a region without any points
+  pub fn new() -> HexSet {
+    HexSet(HashSet::new())
+  }
+
+  /// Return whether the region contains a point
+  pub fn contains<T: Borrow<Point>>(&self, point: &T) -> bool {
+    self.0.contains(point.borrow())
+  }
+
+  /// Add a point, returning whether it was not already present
+  pub fn insert(&mut self, point: Point) -> bool {
+    self.0.insert(point)
+  }
+
+  /// Remove a point, returning whether it was present
+  pub fn remove<T: Borrow<Point>>(&mut self, point: &T) -> bool {
+    self.0.remove(point.borrow())
+  }
+
+  /// Count the points in the region
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Return whether the region has no points
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Iterate over the points in the region
+  pub fn iter(&self) -> hash_set::Iter<'_, Point> {
+    self.0.iter()
+  }
+
+  /// Find the points in either region
+  pub fn union(&self, other: &HexSet) -> HexSet {
+    self.0.union(&other.0).cloned().collect()
+  }
+
+  /// Find the points in both regions
+  pub fn intersection(&self, other: &HexSet) -> HexSet {
+    self.0.intersection(&other.0).cloned().collect()
+  }
+
+  /// Find the points in this region but not the other
+  pub fn difference(&self, other: &HexSet) -> HexSet {
+    self.0.difference(&other.0).cloned().collect()
+  }
+
+  /// Grow the region by adding every point within range of it
+  pub fn dilate(&self, range: i32) -> HexSet {
+    self.iter().flat_map(|point| point.base_range_iter(range)).collect()
+  }
+
+  /// Shrink the region by keeping only points with all points in range inside
+  pub fn erode(&self, range: i32) -> HexSet {
+    self.iter()
+      .filter(|point| point.base_range_iter(range).all(|other| self.contains(&other)))
+      .cloned()
+      .collect()
+  }
+
+  /// Find the points in the region with a neighbor outside of it
+  pub fn boundary(&self) -> HexSet {
+    self.iter()
+      .filter(|point| point.base_ring_iter(1).any(|other| !self.contains(&other)))
+      .cloned()
+      .collect()
+  }
+
+  /// Split the region into groups of points which are connected to each other
+  pub fn components(&self) -> Vec<HexSet> {
+    let mut remaining: HashSet<Point> = self.0.clone();
+    let mut components: Vec<HexSet> = Vec::new();
+
+    while let Some(&start) = remaining.iter().next() {
+      let mut component: HexSet = HexSet::new();
+      let mut fringes: Vec<Point> = vec![ start ];
+
+      remaining.remove(&start);
+
+      while let Some(point) = fringes.pop() {
+        component.insert(point);
+
+        for neighbor in point.base_ring_iter(1) {
+          if remaining.remove(&neighbor) {
+            fringes.push(neighbor);
+          }
+        }
+      }
+
+      components.push(component);
+    }
+
+    components
+  }
+
+  /// Find the sides of points in the region which face points outside of it
+  ///
+  /// Each side is given by the point inside the region and the direction of
+  /// the point outside of it. `Edge::from` converts a side into its edge.
+  pub fn outline(&self) -> Vec<(Point, Direction)> {
+    let mut outline: Vec<(Point, Direction)> = Vec::new();
+
+    for point in self.iter() {
+      for direction in Direction::planar() {
+        if !self.contains(&point.travel(&direction, 1)) {
+          outline.push((*point, direction));
+        }
+      }
+    }
+
+    outline
+  }
+
+  /// Find the outline of each connected group of points as a polygon
+  pub fn polygons(&self) -> Vec<Polygon> {
+    let mut polygons: Vec<Polygon> = Vec::new();
+
+    for component in self.components() {
+      let mut rings: Vec<Vec<PixelPoint>> = component.rings()
+        .into_iter()
+        .map(|ring| ring.into_iter().map(PixelPoint::from).collect())
+        .collect();
+
+      let outer: usize = (0 .. rings.len())
+        .max_by(|&a, &b| {
+          Self::area(&rings[a]).abs().partial_cmp(&Self::area(&rings[b]).abs()).unwrap()
+        })
+        .unwrap();
+
+      let exterior: Vec<PixelPoint> = rings.remove(outer);
+
+      polygons.push(Polygon(exterior, rings));
+    }
+
+    polygons
+  }
+
+  /// Chain the sides of the outline into closed loops of vertices
+  ///
+  /// Every vertex on the outline touches exactly two of its sides, so
+  /// following each side to the next one always returns to the start.
+  fn rings(&self) -> Vec<Vec<Vertex>> {
+    let mut next: HashMap<Vertex, Vertex> = self.outline()
+      .iter()
+      .map(|&(point, direction)| Self::side(&point, &direction))
+      .collect();
+
+    let mut rings: Vec<Vec<Vertex>> = Vec::new();
+
+    while let Some(&start) = next.keys().next() {
+      let mut ring: Vec<Vertex> = Vec::new();
+      let mut vertex: Vertex = start;
+
+      while let Some(following) = next.remove(&vertex) {
+        ring.push(vertex);
+        vertex = following;
+      }
+
+      rings.push(ring);
+    }
+
+    rings
+  }
+
+  /// Return the vertices at either end of a side, in clockwise order
+  fn side(point: &Point, direction: &Direction) -> (Vertex, Vertex) {
+    let corners: [Vertex; 6] = [
+      Vertex(*point, Corner::North),
+      Vertex(point.travel(&Northeast, 1), Corner::South),
+      Vertex(point.travel(&Southeast, 1), Corner::North),
+      Vertex(*point, Corner::South),
+      Vertex(point.travel(&Southwest, 1), Corner::North),
+      Vertex(point.travel(&Northwest, 1), Corner::South),
+    ];
+
+    let index: usize = direction.index();
+
+    (corners[(index + 1) % 6], corners[(index + 2) % 6])
+  }
+
+  /// Find the signed area of a ring, which is positive when it runs clockwise
+  fn area(ring: &[PixelPoint]) -> f32 {
+    let mut area: f32 = 0f32;
+
+    for (index, &PixelPoint(x0, y0)) in ring.iter().enumerate() {
+      let PixelPoint(x1, y1) = ring[(index + 1) % ring.len()];
+
+      area += x0 * y1 - x1 * y0;
+    }
+
+    area / 2f32
+  }
+
+}
+
+impl From<HashSet<Point>> for HexSet {
+  fn from(set: HashSet<Point>) -> HexSet {
+    HexSet(set)
+  }
+}
+
+impl FromIterator<Point> for HexSet {
+  fn from_iter<T: IntoIterator<Item = Point>>(iter: T) -> HexSet {
+    HexSet(iter.into_iter().collect())
+  }
+}
+
+impl IntoIterator for HexSet {
+  type Item = Point;
+  type IntoIter = hash_set::IntoIter<Point>;
+
+  fn into_iter(self) -> hash_set::IntoIter<Point> {
+    self.0.into_iter()
+  }
+}
+
+impl<'a> IntoIterator for &'a HexSet {
+  type Item = &'a Point;
+  type IntoIter = hash_set::Iter<'a, Point>;
+
+  fn into_iter(self) -> hash_set::Iter<'a, Point> {
+    self.0.iter()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const CENTER: &Point = &Point(1, 2, 5);
+
+  #[test]
+  fn set_algebra() {
+    let a: HexSet = CENTER.base_range(1).into();
+    let b: HexSet = Point(2, 2, 5).base_range(1).into();
+
+    assert!(a.union(&b).len() == 10);
+    assert!(a.intersection(&b).len() == 4);
+    assert!(a.difference(&b).len() == 3);
+    assert!(!a.difference(&b).contains(&Point(2, 2, 5)));
+  }
+
+  #[test]
+  fn dilate() {
+    let set: HexSet = vec![ *CENTER ].into_iter().collect();
+
+    assert!(set.dilate(2) == CENTER.base_range(2).into());
+    assert!(set.dilate(0) == set);
+  }
+
+  #[test]
+  fn erode() {
+    let set: HexSet = CENTER.base_range(3).into();
+
+    assert!(set.erode(1) == CENTER.base_range(2).into());
+    assert!(set.erode(3).len() == 1);
+    assert!(set.erode(4).is_empty());
+  }
+
+  #[test]
+  fn boundary() {
+    let set: HexSet = CENTER.base_range(2).into();
+
+    assert!(set.boundary() == CENTER.base_ring(2).into());
+  }
+
+  #[test]
+  fn components() {
+    let a: HexSet = CENTER.base_range(1).into();
+    let b: HexSet = Point(5, 2, 5).base_range(1).into();
+    let c: HexSet = CENTER.base_range(1).iter().map(|p| p + &Point(0, 0, 1)).collect();
+    let set: HexSet = a.union(&b).union(&c);
+
+    let components: Vec<HexSet> = set.components();
+
+    assert!(components.len() == 3);
+    assert!(components.contains(&a));
+    assert!(components.contains(&b));
+    assert!(components.contains(&c));
+  }
+
+  #[test]
+  fn outline() {
+    let set: HexSet = CENTER.base_range(1).into();
+    let outline: Vec<(Point, Direction)> = set.outline();
+
+    assert!(outline.len() == 18);
+    assert!(outline.contains(&(Point(2, 2, 5), East)));
+    assert!(!outline.contains(&(*CENTER, East)));
+  }
+
+  #[test]
+  fn polygons() {
+    let set: HexSet = vec![ *CENTER ].into_iter().collect();
+    let polygons: Vec<Polygon> = set.polygons();
+
+    assert!(polygons.len() == 1);
+    assert!(polygons[0].0.len() == 6);
+    assert!(polygons[0].1.is_empty());
+    assert!(HexSet::area(&polygons[0].0) > 0f32);
+
+    for &PixelPoint(x, y) in &polygons[0].0 {
+      let PixelPoint(cx, cy) = Point::<f32>::from(*CENTER).into();
+      let distance: f32 = ((x - cx) * (x - cx) + (y - cy) * (y - cy)).sqrt();
+
+      assert!((distance - 1f32).abs() < 1e-5);
+    }
+  }
+
+  #[test]
+  fn polygons_with_holes() {
+    let ring: HexSet = CENTER.base_ring(1).into();
+    let other: HexSet = vec![ Point(5, 2, 5) ].into_iter().collect();
+    let polygons: Vec<Polygon> = ring.union(&other).polygons();
+
+    assert!(polygons.len() == 2);
+
+    let Polygon(ref exterior, ref holes) = *polygons.iter().find(|p| !p.1.is_empty()).unwrap();
+
+    assert!(exterior.len() == 18);
+    assert!(holes.len() == 1);
+    assert!(holes[0].len() == 6);
+    assert!(HexSet::area(exterior) > 0f32);
+    assert!(HexSet::area(&holes[0]) < 0f32);
+  }
+}
@@ -0,0 +1,547 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+use enums::Direction;
+use fns::search::{a_star, dijkstra};
+use structs::{BoundingBox, Chunks, Edge, Point, Prism, WallMap};
+use traits::IsPointMap;
+use traits::distance::Base as BaseDistance;
+use traits::travel::Travel;
+
+/// Edges leaving each entrance, grouped by the chunk of the entrance
+type Graph = HashMap<Point, HashMap<Point, Vec<(Point, i32)>>>;
+
+/// An abstract graph over the chunks of a bounded map for finding long paths
+///
+/// Each pair of neighboring chunks is joined by one entrance for every
+/// unbroken stretch of their shared border which can be crossed. Paths are
+/// first found between entrances and then refined within each chunk, so they
+/// are found quickly but are not always the shortest. Only points inside the
+/// bounds and moves between points of the same height are considered.
+///
+/// The graph is kept up to date by invalidating points whose walls change,
+/// either one at a time or from the change log of a `WallMap`, and
+/// invalidated chunks are rebuilt before the next path is found.
+#[derive(Clone, Debug)]
+pub struct Hierarchy {
+  chunks: Chunks,
+  bounds: BoundingBox,
+  entrances: HashMap<Edge, Vec<(Point, Point)>>,
+  graph: Graph,
+  dirty: HashSet<Point>,
+  revision: usize,
+}
+
+impl Hierarchy {
+
+  /// Build the graph for the points of a map inside some bounds
+  pub fn new<U: Borrow<Prism>>(
+    chunks: Chunks,
+    bounds: BoundingBox,
+    map: &HashMap<Point, U>,
+  ) -> Hierarchy {
+    let mut hierarchy: Hierarchy = Hierarchy {
+      chunks,
+      bounds,
+      entrances: HashMap::new(),
+      graph: HashMap::new(),
+      dirty: bounds.points().iter().map(|point| chunks.chunk(point)).collect(),
+      revision: 0,
+    };
+
+    hierarchy.refresh(map);
+
+    hierarchy
+  }
+
+  /// Build the graph for the points of a wall map, starting at its revision
+  pub fn from_walls(chunks: Chunks, bounds: BoundingBox, walls: &WallMap) -> Hierarchy {
+    let mut hierarchy: Hierarchy = Hierarchy::new(chunks, bounds, walls.map());
+
+    hierarchy.revision = walls.revision();
+
+    hierarchy
+  }
+
+  /// Mark a point whose walls have changed so its chunks are rebuilt
+  pub fn invalidate<T: Borrow<Point>>(&mut self, point: &T) {
+    let point: &Point = point.borrow();
+
+    self.dirty.insert(self.chunks.chunk(point));
+
+    for direction in &Direction::planar() {
+      self.dirty.insert(self.chunks.chunk(&point.travel(direction, 1)));
+    }
+  }
+
+  /// Invalidate every point changed on a wall map since the last update
+  ///
+  /// A hierarchy built with `new` starts from the first revision of the map,
+  /// so one built from a map with earlier changes should use `from_walls`.
+  pub fn update(&mut self, walls: &WallMap) {
+    for point in walls.changes_since(self.revision) {
+      self.invalidate(&point);
+    }
+
+    self.revision = walls.revision();
+  }
+
+  /// Find a path between two points of the same height
+  ///
+  /// The path includes both ends, and is `None` if either point is outside
+  /// of the bounds or the points are not connected.
+  pub fn path<T: Borrow<Point>, U: Borrow<Point>, V: Borrow<Prism>>(
+    &mut self,
+    start: &T,
+    goal: &U,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>> {
+    let start: Point = *start.borrow();
+    let goal: Point = *goal.borrow();
+
+    if !self.bounds.contains(&start) || !self.bounds.contains(&goal) || start.2 != goal.2 {
+      return None;
+    }
+
+    self.refresh(map);
+
+    if self.chunks.chunk(&start) == self.chunks.chunk(&goal) {
+      if let Some(path) = self.local_path(&start, &goal, map) {
+        return Some(path);
+      }
+    }
+
+    let starts: HashMap<Point, (i32, Option<Point>)> = self.local_costs(&start, map);
+    let goals: HashMap<Point, (i32, Option<Point>)> = self.local_costs(&goal, map);
+    let graph: &Graph = &self.graph;
+    let chunks: &Chunks = &self.chunks;
+
+    let reaches_node = |costs: &HashMap<Point, (i32, Option<Point>)>| {
+      costs.keys().any(|point| Self::is_node(graph, chunks, point))
+    };
+
+    if !reaches_node(&starts) || !reaches_node(&goals) {
+      return self.direct_path(&start, &goal, map);
+    }
+
+    let neighbors = |point: &Point| {
+      let mut neighbors: Vec<(Point, i32)> = Vec::new();
+
+      if *point == start {
+        for (node, &(cost, _)) in &starts {
+          if Self::is_node(graph, chunks, node) {
+            neighbors.push((*node, cost));
+          }
+        }
+      }
+
+      if let Some(edges) = graph.get(&chunks.chunk(point)).and_then(|nodes| nodes.get(point)) {
+        neighbors.extend(edges.iter().cloned());
+      }
+
+      if let Some(&(cost, _)) = goals.get(point) {
+        neighbors.push((goal, cost));
+      }
+
+      neighbors
+    };
+
+    let (nodes, _) = a_star(start, |point| *point == goal, neighbors, |point| {
+      point.base_distance(&goal)
+    })?;
+
+    let mut path: Vec<Point> = vec![ start ];
+
+    for pair in nodes.windows(2) {
+      let segment: Vec<Point> = match self.chunks.chunk(&pair[0]) == self.chunks.chunk(&pair[1]) {
+        true => self.local_path(&pair[0], &pair[1], map)?,
+        false => pair.to_vec(),
+      };
+
+      path.extend(segment.into_iter().skip(1));
+    }
+
+    Some(path)
+  }
+
+  /// Rebuild every chunk which has been invalidated
+  fn refresh<U: Borrow<Prism>>(&mut self, map: &HashMap<Point, U>) {
+    let dirty: Vec<Point> = self.dirty.drain().collect();
+    let mut affected: HashSet<Point> = HashSet::new();
+
+    for chunk in &dirty {
+      for direction in &Direction::planar() {
+        let border: Edge = Edge::from((chunk, direction));
+        let entrances: Vec<(Point, Point)> = self.border(&border, map);
+
+        match entrances.is_empty() {
+          true => self.entrances.remove(&border),
+          false => self.entrances.insert(border, entrances),
+        };
+
+        affected.insert(chunk.travel(direction, 1));
+      }
+
+      affected.insert(*chunk);
+    }
+
+    for chunk in &affected {
+      self.rebuild(chunk, map);
+    }
+  }
+
+  /// Find one crossing for each unbroken stretch of a border between chunks
+  ///
+  /// Two crossings share a stretch when their points are neighbors without a
+  /// wall between them on both sides of the border.
+  fn border<U: Borrow<Prism>>(
+    &self,
+    border: &Edge,
+    map: &HashMap<Point, U>,
+  ) -> Vec<(Point, Point)> {
+    let &Edge(chunk, direction) = border;
+    let other: Point = chunk.travel(&direction, 1);
+
+    let mut crossings: Vec<(Point, Point)> = Vec::new();
+
+    for point in self.points(&chunk) {
+      for direction in &Direction::planar() {
+        let neighbor: Point = point.travel(direction, 1);
+
+        if self.chunks.chunk(&neighbor) == other &&
+          self.bounds.contains(&neighbor) &&
+          !map.has_wall_between(&point, &neighbor)
+        {
+          crossings.push((point, neighbor));
+        }
+      }
+    }
+
+    let mut entrances: Vec<(Point, Point)> = Vec::new();
+
+    while let Some(first) = crossings.pop() {
+      let mut stretch: Vec<(Point, Point)> = vec![ first ];
+      let mut index: usize = 0;
+
+      while index < stretch.len() {
+        let (point, neighbor) = stretch[index];
+
+        let (joined, apart): (Vec<_>, Vec<_>) = crossings
+          .into_iter()
+          .partition(|&(other, across)| {
+            point.base_distance(&other) <= 1 &&
+            neighbor.base_distance(&across) <= 1 &&
+            !map.has_wall_between(&point, &other) &&
+            !map.has_wall_between(&neighbor, &across)
+          });
+
+        stretch.extend(joined);
+        crossings = apart;
+        index += 1;
+      }
+
+      stretch.sort_by_key(|&(Point(q, r, _), Point(nq, nr, _))| (q, r, nq, nr));
+      entrances.push(stretch[stretch.len() / 2]);
+    }
+
+    entrances
+  }
+
+  /// Rebuild the edges leaving the entrances of a chunk
+  fn rebuild<U: Borrow<Prism>>(&mut self, chunk: &Point, map: &HashMap<Point, U>) {
+    let mut nodes: HashMap<Point, Vec<(Point, i32)>> = HashMap::new();
+
+    for direction in &Direction::planar() {
+      let border: Edge = Edge::from((chunk, direction));
+
+      for &(point, neighbor) in self.entrances.get(&border).into_iter().flatten() {
+        let (inside, outside) = match self.chunks.chunk(&point) == *chunk {
+          true => (point, neighbor),
+          false => (neighbor, point),
+        };
+
+        nodes.entry(inside).or_insert_with(Vec::new).push((outside, 1));
+      }
+    }
+
+    let inside: Vec<Point> = nodes.keys().cloned().collect();
+
+    for node in &inside {
+      let costs: HashMap<Point, (i32, Option<Point>)> = self.local_costs(node, map);
+
+      for other in &inside {
+        if let Some(&(cost, _)) = costs.get(other).filter(|_| other != node) {
+          nodes.get_mut(node).unwrap().push((*other, cost));
+        }
+      }
+    }
+
+    match nodes.is_empty() {
+      true => self.graph.remove(chunk),
+      false => self.graph.insert(*chunk, nodes),
+    };
+  }
+
+  /// Find a shortest path between two points without using the graph
+  fn direct_path<U: Borrow<Prism>>(
+    &self,
+    start: &Point,
+    goal: &Point,
+    map: &HashMap<Point, U>,
+  ) -> Option<Vec<Point>> {
+    let neighbors = |point: &Point| {
+      Direction::planar()
+        .iter()
+        .map(|direction| point.travel(direction, 1))
+        .filter(|neighbor| self.bounds.contains(neighbor))
+        .filter(|neighbor| !map.has_wall_between(point, neighbor))
+        .map(|neighbor| (neighbor, 1))
+        .collect()
+    };
+    let heuristic = |point: &Point| point.base_distance(goal);
+
+    a_star(*start, |point| point == goal, neighbors, heuristic).map(|(path, _)| path)
+  }
+
+  /// Find a shortest path between two points without leaving their chunk
+  fn local_path<U: Borrow<Prism>>(
+    &self,
+    start: &Point,
+    goal: &Point,
+    map: &HashMap<Point, U>,
+  ) -> Option<Vec<Point>> {
+    let neighbors = |point: &Point| self.local_neighbors(point, map);
+    let heuristic = |point: &Point| point.base_distance(goal);
+
+    a_star(*start, |point| point == goal, neighbors, heuristic).map(|(path, _)| path)
+  }
+
+  /// Find the cost of reaching every point in the chunk of a point
+  fn local_costs<U: Borrow<Prism>>(
+    &self,
+    start: &Point,
+    map: &HashMap<Point, U>,
+  ) -> HashMap<Point, (i32, Option<Point>)> {
+    dijkstra(*start, self.chunks.size(), |point| self.local_neighbors(point, map))
+  }
+
+  /// Find the neighbors of a point which can be reached inside its chunk
+  fn local_neighbors<U: Borrow<Prism>>(
+    &self,
+    point: &Point,
+    map: &HashMap<Point, U>,
+  ) -> Vec<(Point, i32)> {
+    let chunk: Point = self.chunks.chunk(point);
+
+    Direction::planar()
+      .iter()
+      .map(|direction| point.travel(direction, 1))
+      .filter(|neighbor| self.bounds.contains(neighbor))
+      .filter(|neighbor| self.chunks.chunk(neighbor) == chunk)
+      .filter(|neighbor| !map.has_wall_between(point, neighbor))
+      .map(|neighbor| (neighbor, 1))
+      .collect()
+  }
+
+  /// Find the points of a chunk inside the bounds
+  fn points(&self, chunk: &Point) -> Vec<Point> {
+    self.chunks
+      .points(chunk)
+      .into_iter()
+      .filter(|point| self.bounds.contains(point))
+      .collect()
+  }
+
+  /// Return whether a point is an entrance of its chunk
+  fn is_node(
+    graph: &Graph,
+    chunks: &Chunks,
+    point: &Point,
+  ) -> bool {
+    graph
+      .get(&chunks.chunk(point))
+      .is_some_and(|nodes| nodes.contains_key(point))
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+  use traits::path::Base as BasePath;
+
+  fn bounds() -> BoundingBox {
+    BoundingBox::from_points(vec![ Point(-8, 0, 0), Point(8, 0, 0), Point(0, -4, 0), Point(0, 4, 0) ])
+      .unwrap()
+      .expand(4, 0)
+  }
+
+  fn is_valid<U: Borrow<Prism>>(path: &[Point], map: &HashMap<Point, U>) -> bool {
+    path.windows(2).all(|pair| {
+      pair[0].base_distance(&pair[1]) == 1 && !map.has_wall_between(&pair[0], &pair[1])
+    }) && path.iter().all(|point| bounds().contains(point))
+  }
+
+  fn wall() -> Vec<Prism> {
+    bounds()
+      .points()
+      .into_iter()
+      .filter_map(|point| match point.0 {
+        0 => Some(Prism(point, 1, 0, 0, 0)),
+        1 => Some(Prism(point, 0, 0, 1, 0)),
+        _ => None,
+      })
+      .collect()
+  }
+
+  #[test]
+  fn path() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let mut hierarchy: Hierarchy = Hierarchy::new(Chunks(2), bounds(), &map);
+
+    let start: Point = Point(-9, 2, 0);
+    let goal: Point = Point(9, -3, 0);
+    let path: Vec<Point> = hierarchy.path(&start, &goal, &map).unwrap();
+    let shortest: Vec<Point> = start.base_path(&goal, 100, &map).unwrap();
+
+    assert!(path[0] == start);
+    assert!(path[path.len() - 1] == goal);
+    assert!(is_valid(&path, &map));
+    assert!(path.len() <= shortest.len() + shortest.len() / 2);
+  }
+
+  #[test]
+  fn path_within_chunk() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let mut hierarchy: Hierarchy = Hierarchy::new(Chunks(2), bounds(), &map);
+
+    assert!(hierarchy.path(&Point(0, 0, 0), &Point(0, 0, 0), &map) == Some(vec![ Point(0, 0, 0) ]));
+    assert!(hierarchy.path(&Point(-1, 0, 0), &Point(1, 0, 0), &map).unwrap().len() == 3);
+  }
+
+  #[test]
+  fn path_outside_bounds() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let mut hierarchy: Hierarchy = Hierarchy::new(Chunks(2), bounds(), &map);
+
+    assert!(hierarchy.path(&Point(0, 0, 0), &Point(20, 0, 0), &map).is_none());
+    assert!(hierarchy.path(&Point(0, 0, 0), &Point(0, 0, 1), &map).is_none());
+  }
+
+  #[test]
+  fn invalidate() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+    let mut hierarchy: Hierarchy = Hierarchy::new(Chunks(2), bounds(), &map);
+
+    let start: Point = Point(-6, 0, 0);
+    let goal: Point = Point(6, 0, 0);
+
+    assert!(hierarchy.path(&start, &goal, &map).is_some());
+
+    for prism in wall() {
+      hierarchy.invalidate(&prism.0);
+      map.insert_walled_point(prism);
+    }
+
+    assert!(hierarchy.path(&start, &goal, &map).is_none());
+
+    let gap: Point = Point(0, 3, 0);
+
+    map.insert_walled_point(Prism(gap, 0, 0, 0, 0));
+    hierarchy.invalidate(&gap);
+
+    let path: Vec<Point> = hierarchy.path(&start, &goal, &map).unwrap();
+
+    assert!(is_valid(&path, &map));
+    assert!(path.contains(&gap));
+    assert!(path.contains(&gap.travel(&East, 1)));
+  }
+
+  #[test]
+  fn update() {
+    let mut walls: WallMap = WallMap::new();
+    let mut hierarchy: Hierarchy = Hierarchy::from_walls(Chunks(2), bounds(), &walls);
+
+    let start: Point = Point(-6, 0, 0);
+    let goal: Point = Point(6, 0, 0);
+
+    for prism in wall() {
+      walls.insert_walled_point(prism);
+    }
+
+    hierarchy.update(&walls);
+
+    assert!(hierarchy.path(&start, &goal, walls.map()).is_none());
+
+    walls.remove_walled_point(&Point(0, 3, 0));
+    hierarchy.update(&walls);
+
+    assert!(hierarchy.path(&start, &goal, walls.map()).is_some());
+  }
+
+  #[test]
+  fn from_walls() {
+    let mut walls: WallMap = WallMap::new();
+
+    for prism in wall() {
+      walls.insert_walled_point(prism);
+    }
+
+    let mut hierarchy: Hierarchy = Hierarchy::from_walls(Chunks(2), bounds(), &walls);
+
+    hierarchy.update(&walls);
+
+    assert!(hierarchy.revision == walls.revision());
+    assert!(hierarchy.dirty.is_empty());
+    assert!(hierarchy.path(&Point(-6, 0, 0), &Point(6, 0, 0), walls.map()).is_none());
+  }
+
+  fn enclose(map: &mut HashMap<Point, Prism>, point: &Point, open: &Direction) {
+    for direction in &Direction::planar() {
+      if direction == open {
+        continue;
+      }
+
+      let (owner, side) = match *direction {
+        East | Southeast | Southwest => (*point, *direction),
+        _ => (point.travel(direction, 1), direction.opposite()),
+      };
+
+      let prism: &mut Prism = map.entry(owner).or_insert(Prism(owner, 0, 0, 0, 0));
+
+      match side {
+        East => prism.1 = 1,
+        Southeast => prism.2 = 1,
+        _ => prism.3 = 1,
+      };
+    }
+  }
+
+  #[test]
+  fn path_through_walled_crossing() {
+    let chunks: Chunks = Chunks(2);
+    let center: Point = Point(0, 0, 0);
+    let bounds: BoundingBox = BoundingBox::from_points(vec![ center ]).unwrap().expand(5, 0);
+
+    for point in chunks.points(&center) {
+      for direction in &Direction::planar() {
+        let across: Point = point.travel(direction, 1);
+
+        if chunks.chunk(&across) == center {
+          continue;
+        }
+
+        let mut map: HashMap<Point, Prism> = HashMap::new();
+
+        enclose(&mut map, &point, direction);
+        enclose(&mut map, &across, &direction.opposite());
+
+        let mut hierarchy: Hierarchy = Hierarchy::new(chunks, bounds, &map);
+        let path: Vec<Point> = hierarchy.path(&point, &across, &map).unwrap();
+
+        assert!(path == vec![ point, across ]);
+      }
+    }
+  }
+}
@@ -1,10 +1,37 @@
 pub mod line;
+pub mod range;
+pub mod ring;
+pub mod spiral;
 
+mod bounding_box;
+mod chunks;
+mod direction_set;
+mod edge;
+mod footprint;
+mod hex_set;
+mod hierarchy;
 mod pixel_point;
 mod point;
+mod polygon;
 mod prism;
+mod reservations;
+mod transform;
+mod vertex;
+mod wall_map;
 
+pub use self::bounding_box::BoundingBox;
+pub use self::chunks::Chunks;
+pub use self::direction_set::DirectionSet;
+pub use self::edge::Edge;
+pub use self::footprint::Footprint;
+pub use self::hex_set::HexSet;
+pub use self::hierarchy::Hierarchy;
 pub use self::pixel_point::PixelPoint;
 pub use self::point::Point;
+pub use self::polygon::Polygon;
 pub use self::prism::Prism;
+pub use self::reservations::Reservations;
+pub use self::transform::Transform;
+pub use self::vertex::Vertex;
+pub use self::wall_map::WallMap;
 
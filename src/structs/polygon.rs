@@ -0,0 +1,9 @@
+use structs::PixelPoint;
+
+/// A closed shape on a screen, made of an outer ring and any number of holes
+///
+/// The outer ring runs clockwise on a screen where y increases downward, and
+/// each hole runs counterclockwise. The last point of a ring connects back to
+/// the first.
+#[derive(Debug, PartialEq)]
+pub struct Polygon(pub Vec<PixelPoint>, pub Vec<Vec<PixelPoint>>);
@@ -1,5 +1,5 @@
 use enums::Direction;
-use structs::Point;
+use structs::{DirectionSet, Point};
 
 /// A prism is a point with walls
 ///
@@ -31,6 +31,48 @@ impl Prism {
 
     result
   }
+
+  /// Return the strength of the wall in the provided direction
+  ///
+  /// If the direction is not one of the four directions, zero will always be
+  /// returned.
+  pub fn wall(&self, direction: &Direction) -> i32 {
+    let &Prism(_, e, se, sw, d) = self;
+
+    match *direction {
+      Direction::East      => e,
+      Direction::Southeast => se,
+      Direction::Southwest => sw,
+      Direction::Down      => d,
+      _ => 0,
+    }
+  }
+
+  /// Set the strength of the wall in the provided direction
+  ///
+  /// If the direction is not one of the four directions, the prism will not
+  /// be changed.
+  pub fn set_wall(&mut self, direction: &Direction, strength: i32) {
+    let &mut Prism(_, ref mut e, ref mut se, ref mut sw, ref mut d) = self;
+
+    match *direction {
+      Direction::East      => *e = strength,
+      Direction::Southeast => *se = strength,
+      Direction::Southwest => *sw = strength,
+      Direction::Down      => *d = strength,
+      _ => (),
+    }
+  }
+
+  /// Return the set of directions in which there is a wall
+  ///
+  /// Only the four directions stored on the prism can be in the set.
+  pub fn walls(&self) -> DirectionSet {
+    Direction::to_vec()
+      .into_iter()
+      .filter(|direction| self.has_wall(direction))
+      .collect()
+  }
 }
 
 #[cfg(test)]
@@ -45,4 +87,41 @@ mod tests {
     assert!(prism.has_wall(&Direction::East));
     assert!(!prism.has_wall(&Direction::Southeast));
   }
+
+  #[test]
+  fn wall() {
+    let point: Point = Point(1, 2, 5);
+    let prism: Prism = Prism(point, 1, 2, 3, 4);
+
+    assert!(1 == prism.wall(&Direction::East));
+    assert!(2 == prism.wall(&Direction::Southeast));
+    assert!(3 == prism.wall(&Direction::Southwest));
+    assert!(4 == prism.wall(&Direction::Down));
+    assert!(0 == prism.wall(&Direction::West));
+  }
+
+  #[test]
+  fn set_wall() {
+    let point: Point = Point(1, 2, 5);
+    let mut prism: Prism = Prism(point, 0, 0, 0, 0);
+
+    prism.set_wall(&Direction::Southwest, 3);
+    prism.set_wall(&Direction::Up, 3);
+
+    let Prism(_, e, se, sw, d) = prism;
+
+    assert!((0, 0, 3, 0) == (e, se, sw, d));
+  }
+
+  #[test]
+  fn walls() {
+    let point: Point = Point(1, 2, 5);
+    let prism: Prism = Prism(point, 1, 0, 2, 3);
+    let walls: DirectionSet = prism.walls();
+
+    assert!(walls.contains(&Direction::East));
+    assert!(walls.contains(&Direction::Southwest));
+    assert!(walls.contains(&Direction::Down));
+    assert!(walls.len() == 3);
+  }
 }
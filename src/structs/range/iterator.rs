@@ -0,0 +1,157 @@
+use std::borrow::Borrow;
+use std::cmp::{max, min};
+use std::iter;
+
+use enums::Direction;
+use structs::Point;
+use traits::travel::Travel;
+
+/// A range iterator returns points within a manhattan distance of a point
+///
+/// The points are returned in layers, starting at the height of the center and
+/// then alternating up and down while moving away from it. Each layer is
+/// returned in lines from west to east, and each line from southeast to
+/// northwest.
+///
+/// A range which is not vertical only has the layer at the height of the
+/// center.
+pub struct Iterator {
+  center: Point,
+  range: i32,
+  vertical: bool,
+  layer: i32,
+  layer_center: Point,
+  layer_range: i32,
+  dq: i32,
+  ds: i32,
+}
+
+impl Iterator {
+  /// Create a new range iterator
+  pub fn new<T: Borrow<Point>>(center: &T, range: i32, vertical: bool) -> Iterator {
+    let center: Point = *center.borrow();
+
+    Iterator {
+      center,
+      range,
+      vertical,
+      layer: 0,
+      layer_center: center,
+      layer_range: range,
+      dq: -range,
+      ds: Self::lower(-range, range),
+    }
+  }
+
+  /// Return the smallest S offset in the line at a Q offset
+  fn lower(dq: i32, range: i32) -> i32 {
+    max(-range, -dq - range)
+  }
+
+  /// Return the largest S offset in the line at a Q offset
+  fn upper(dq: i32, range: i32) -> i32 {
+    min(range, -dq + range)
+  }
+
+  /// Move to the next layer, returning false if there are no more layers
+  ///
+  /// Layers alternate up and down: 0, 1, -1, 2, -2 and so on.
+  fn advance(&mut self) -> bool {
+    self.layer += 1;
+
+    if !self.vertical || self.layer > self.range * 2 {
+      return false;
+    }
+
+    let offset = match self.layer % 2 {
+      1 => (self.layer + 1) / 2,
+      _ => -self.layer / 2,
+    };
+
+    self.layer_center = self.center.travel(&Direction::Up, offset);
+    self.layer_range = self.range - offset.abs();
+    self.dq = -self.layer_range;
+    self.ds = Self::lower(self.dq, self.layer_range);
+
+    true
+  }
+}
+
+impl iter::Iterator for Iterator {
+  type Item = Point;
+
+  /// Find the next point in the range
+  fn next(&mut self) -> Option<Point> {
+    if self.range < 0 {
+      return None;
+    }
+
+    while self.dq > self.layer_range {
+      if !self.advance() {
+        return None;
+      }
+    }
+
+    let (dq, ds) = (self.dq, self.ds);
+    let point: Point = &self.layer_center + &Point(dq, -dq - ds, 0);
+
+    if ds < Self::upper(dq, self.layer_range) {
+      self.ds += 1;
+    } else {
+      self.dq += 1;
+      self.ds = Self::lower(self.dq, self.layer_range);
+    }
+
+    Some(point)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashSet;
+  use traits::distance::{Base, Distance};
+
+  const CENTER: &Point = &Point(1, 2, 5);
+
+  #[test]
+  fn next_returns_range() {
+    let mut iter = Iterator::new(CENTER, 1, false);
+
+    assert!(Point(0, 3, 5) == iter.next().unwrap());
+    assert!(Point(0, 2, 5) == iter.next().unwrap());
+    assert!(Point(1, 3, 5) == iter.next().unwrap());
+    assert!(Point(1, 2, 5) == iter.next().unwrap());
+    assert!(Point(1, 1, 5) == iter.next().unwrap());
+    assert!(Point(2, 2, 5) == iter.next().unwrap());
+    assert!(Point(2, 1, 5) == iter.next().unwrap());
+    assert!(iter.next().is_none());
+  }
+
+  #[test]
+  fn next_returns_unique_points() {
+    let points: Vec<Point> = Iterator::new(CENTER, 3, false).collect();
+    let set: HashSet<Point> = points.iter().cloned().collect();
+
+    assert!(points.len() == 37);
+    assert!(set.len() == 37);
+    assert!(points.iter().all(|point| CENTER.base_distance(point) <= 3));
+  }
+
+  #[test]
+  fn next_vertical() {
+    let points: Vec<Point> = Iterator::new(CENTER, 3, true).collect();
+    let set: HashSet<Point> = points.iter().cloned().collect();
+
+    assert!(points.len() == 91);
+    assert!(set.len() == 91);
+    assert!(points.iter().all(|point| CENTER.distance(point) <= 3));
+    assert!(Point(1, 2, 8) == points[89]);
+    assert!(Point(1, 2, 2) == points[90]);
+  }
+
+  #[test]
+  fn next_negative_range() {
+    assert!(Iterator::new(CENTER, -1, true).next().is_none());
+  }
+}
@@ -0,0 +1,251 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+use enums::Direction;
+use fns::search::a_star;
+use structs::{Point, Prism};
+use traits::IsPointMap;
+use traits::distance::Base as BaseDistance;
+use traits::travel::Travel;
+
+/// A table of the points agents will occupy at each tick
+///
+/// Paths are planned one agent at a time, each avoiding the points reserved
+/// by the agents planned before it. An agent never enters a point another
+/// agent holds at the same tick, never swaps places with another agent, and
+/// stays at the end of its path once it arrives. Paths are made of one point
+/// per tick, so an agent which waits appears at the same point more than once.
+/// Only moves between points of the same height are considered.
+#[derive(Clone, Debug, Default)]
+pub struct Reservations {
+  points: HashSet<(Point, i32)>,
+  moves: HashSet<(Point, Point, i32)>,
+  parked: HashMap<Point, i32>,
+  latest: HashMap<Point, i32>,
+}
+
+impl Reservations {
+
+  /// Create a table without any reservations
+  pub fn new() -> Reservations {
+    Reservations::default()
+  }
+
+  /// Return whether no agent holds a point at a tick
+  pub fn is_free<T: Borrow<Point>>(&self, point: &T, tick: i32) -> bool {
+    let point: &Point = point.borrow();
+
+    !self.points.contains(&(*point, tick)) &&
+    self.parked.get(point).is_none_or(|&parked| tick < parked)
+  }
+
+  /// Reserve the points of a path starting at a tick
+  ///
+  /// The agent is reserved at the last point of the path from then on.
+  pub fn reserve<T: Borrow<Point>>(&mut self, path: &[T], tick: i32) {
+    for (index, point) in path.iter().enumerate() {
+      let point: Point = *point.borrow();
+      let at: i32 = tick + index as i32;
+
+      self.points.insert((point, at));
+
+      let latest: &mut i32 = self.latest.entry(point).or_insert(at);
+
+      *latest = (*latest).max(at);
+    }
+
+    for (index, pair) in path.windows(2).enumerate() {
+      self.moves.insert((*pair[0].borrow(), *pair[1].borrow(), tick + index as i32));
+    }
+
+    if let Some(last) = path.last() {
+      self.parked.insert(*last.borrow(), tick + path.len() as i32 - 1);
+    }
+  }
+
+  /// Find a path which avoids every reservation, starting at a tick
+  ///
+  /// The path ends once the agent reaches the goal and can stay there, and
+  /// is `None` if that can not happen within a number of ticks.
+  pub fn path<T: Borrow<Point>, U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    start: &T,
+    goal: &U,
+    tick: i32,
+    range: i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>> {
+    let start: Point = *start.borrow();
+    let goal: Point = *goal.borrow();
+
+    let neighbors = |&(point, at): &(Point, i32)| {
+      let mut neighbors: Vec<((Point, i32), i32)> = Vec::new();
+
+      if at - tick >= range {
+        return neighbors;
+      }
+
+      let mut moves: Vec<Point> = vec![ point ];
+
+      moves.extend(
+        Direction::planar()
+          .iter()
+          .map(|direction| point.travel(direction, 1))
+          .filter(|neighbor| !map.has_wall_between(&point, neighbor))
+      );
+
+      for next in moves {
+        if self.is_free(&next, at + 1) && !self.moves.contains(&(next, point, at)) {
+          neighbors.push(((next, at + 1), 1));
+        }
+      }
+
+      neighbors
+    };
+
+    let is_goal = |&(point, at): &(Point, i32)| {
+      point == goal && self.latest.get(&goal).is_none_or(|&latest| latest < at)
+    };
+
+    let heuristic = |&(point, _): &(Point, i32)| point.base_distance(&goal);
+
+    a_star((start, tick), is_goal, neighbors, heuristic)
+      .map(|(path, _)| path.into_iter().map(|(point, _)| point).collect())
+  }
+
+  /// Plan and reserve paths for agents in order, starting at a tick
+  ///
+  /// Each agent is given as its start and goal. An agent without a path is
+  /// reserved at its start from then on, so the agents after it avoid it.
+  pub fn plan<T: Borrow<Point>, U: Borrow<Prism>>(
+    &mut self,
+    agents: &[(T, T)],
+    tick: i32,
+    range: i32,
+    map: &HashMap<Point, U>,
+  ) -> Vec<Option<Vec<Point>>> {
+    let mut paths: Vec<Option<Vec<Point>>> = Vec::new();
+
+    for (start, goal) in agents {
+      let path: Option<Vec<Point>> = self.path(start, goal, tick, range, map);
+
+      match path {
+        Some(ref path) => self.reserve(path, tick),
+        None => self.reserve(&[ *start.borrow() ], tick),
+      }
+
+      paths.push(path);
+    }
+
+    paths
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn corridor() -> HashMap<Point, Prism> {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    for q in -1..7 {
+      map.insert_walled_point(Prism(Point(q, -1, 0), 0, 1, 1, 0));
+      map.insert_walled_point(Prism(Point(q, 0, 0), 0, 1, 1, 0));
+    }
+
+    map
+  }
+
+  fn is_valid(paths: &[Vec<Point>]) -> bool {
+    let ticks: usize = paths.iter().map(|path| path.len()).max().unwrap();
+    let at = |path: &Vec<Point>, tick: usize| path[tick.min(path.len() - 1)];
+
+    (0 .. ticks).all(|tick| {
+      paths.iter().enumerate().all(|(i, a)| {
+        paths.iter().enumerate().skip(i + 1).all(|(_, b)| {
+          at(a, tick) != at(b, tick) &&
+          (tick == 0 || at(a, tick) != at(b, tick - 1) || at(b, tick) != at(a, tick - 1))
+        })
+      })
+    })
+  }
+
+  #[test]
+  fn reserve() {
+    let mut reservations: Reservations = Reservations::new();
+
+    reservations.reserve(&[ Point(0, 0, 0), Point(1, 0, 0) ], 3);
+
+    assert!(!reservations.is_free(&Point(0, 0, 0), 3));
+    assert!(reservations.is_free(&Point(0, 0, 0), 4));
+    assert!(reservations.is_free(&Point(1, 0, 0), 3));
+    assert!(!reservations.is_free(&Point(1, 0, 0), 4));
+    assert!(!reservations.is_free(&Point(1, 0, 0), 40));
+  }
+
+  #[test]
+  fn path() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let mut reservations: Reservations = Reservations::new();
+
+    reservations.reserve(&[ Point(1, 0, 0), Point(1, 0, 0), Point(1, 0, 0), Point(1, 1, 0) ], 0);
+
+    let path: Vec<Point> = reservations.path(&Point(0, 0, 0), &Point(2, 0, 0), 0, 10, &map).unwrap();
+
+    assert!(path[0] == Point(0, 0, 0));
+    assert!(path[path.len() - 1] == Point(2, 0, 0));
+    assert!(path.len() == 4);
+    assert!(!path.contains(&Point(1, 0, 0)) || path[3] == Point(1, 0, 0));
+  }
+
+  #[test]
+  fn path_swap() {
+    let map: HashMap<Point, Prism> = corridor();
+    let mut reservations: Reservations = Reservations::new();
+
+    reservations.reserve(&[ Point(1, 0, 0), Point(0, 0, 0) ], 0);
+
+    let path: Option<Vec<Point>> = reservations.path(&Point(0, 0, 0), &Point(1, 0, 0), 0, 1, &map);
+
+    assert!(path.is_none());
+  }
+
+  #[test]
+  fn plan() {
+    let map: HashMap<Point, Prism> = corridor();
+    let mut reservations: Reservations = Reservations::new();
+
+    let agents: Vec<(Point, Point)> = vec![
+      (Point(0, 0, 0), Point(5, 0, 0)),
+      (Point(5, 0, 0), Point(0, 0, 0)),
+    ];
+
+    let paths: Vec<Vec<Point>> = reservations
+      .plan(&agents, 0, 20, &map)
+      .into_iter()
+      .map(|path| path.unwrap())
+      .collect();
+
+    assert!(paths[0][0] == Point(0, 0, 0));
+    assert!(paths[1][paths[1].len() - 1] == Point(0, 0, 0));
+    assert!(is_valid(&paths));
+  }
+
+  #[test]
+  fn plan_blocked() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let mut reservations: Reservations = Reservations::new();
+
+    let agents: Vec<(Point, Point)> = vec![
+      (Point(0, 0, 0), Point(1, 0, 0)),
+      (Point(3, 0, 0), Point(1, 0, 0)),
+    ];
+
+    let paths: Vec<Option<Vec<Point>>> = reservations.plan(&agents, 0, 10, &map);
+
+    assert!(paths[0].is_some());
+    assert!(paths[1].is_none());
+    assert!(!reservations.is_free(&Point(3, 0, 0), 5));
+  }
+}
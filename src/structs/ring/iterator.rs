@@ -0,0 +1,104 @@
+use std::borrow::Borrow;
+use std::iter;
+
+use enums::Direction;
+use structs::Point;
+use traits::travel::Travel;
+
+/// A ring iterator returns points at the same height in a ring around a point
+///
+/// The ring starts at the northwest corner and moves clockwise. A ring with a
+/// radius of zero has no points.
+pub struct Iterator {
+  point: Point,
+  radius: i32,
+  side: usize,
+  step: i32,
+}
+
+impl Iterator {
+  /// Create a new ring iterator
+  pub fn new<T: Borrow<Point>>(center: &T, radius: i32) -> Iterator {
+    Iterator {
+      point: center.travel(&Direction::Northwest, radius),
+      radius,
+      side: 0,
+      step: 0,
+    }
+  }
+
+  /// Count the points which have not been returned yet
+  fn remaining(&self) -> usize {
+    if self.radius <= 0 {
+      return 0;
+    }
+
+    (self.radius * (6 - self.side as i32) - self.step) as usize
+  }
+}
+
+impl iter::Iterator for Iterator {
+  type Item = Point;
+
+  /// Find the next point in the ring
+  fn next(&mut self) -> Option<Point> {
+    if self.remaining() == 0 {
+      return None;
+    }
+
+    let point: Point = self.point;
+
+    self.point = point.travel(&Direction::planar()[self.side], 1);
+    self.step += 1;
+
+    if self.step == self.radius {
+      self.step = 0;
+      self.side += 1;
+    }
+
+    Some(point)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.remaining(), Some(self.remaining()))
+  }
+}
+
+impl iter::ExactSizeIterator for Iterator {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const CENTER: &Point = &Point(1, 2, 5);
+
+  #[test]
+  fn next_returns_ring() {
+    let mut iter = Iterator::new(CENTER, 1);
+
+    assert!(Point(1, 1, 5) == iter.next().unwrap());
+    assert!(Point(2, 1, 5) == iter.next().unwrap());
+    assert!(Point(2, 2, 5) == iter.next().unwrap());
+    assert!(Point(1, 3, 5) == iter.next().unwrap());
+    assert!(Point(0, 3, 5) == iter.next().unwrap());
+    assert!(Point(0, 2, 5) == iter.next().unwrap());
+    assert!(iter.next().is_none());
+  }
+
+  #[test]
+  fn next_radius_zero() {
+    assert!(Iterator::new(CENTER, 0).next().is_none());
+  }
+
+  #[test]
+  fn len() {
+    let mut iter = Iterator::new(CENTER, 3);
+
+    assert!(18 == iter.len());
+
+    iter.next();
+
+    assert!(17 == iter.len());
+    assert!(17 == iter.count());
+  }
+}
@@ -0,0 +1,5 @@
+mod iterator;
+mod shell;
+
+pub use self::iterator::Iterator;
+pub use self::shell::Shell;
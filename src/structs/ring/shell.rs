@@ -0,0 +1,147 @@
+use std::borrow::Borrow;
+use std::iter;
+
+use enums::Direction;
+use structs::Point;
+use structs::ring::Iterator;
+use traits::travel::Travel;
+
+/// A shell iterator returns points at a manhattan distance from a point
+///
+/// The shell is made of rings at each height, starting at the height of the
+/// center and then alternating up and down while moving away from it. Each
+/// ring is returned in the order of a ring iterator, and a ring with a radius
+/// of zero is the single point at its center.
+///
+/// A shell which is not vertical only has the ring at the height of the center.
+pub struct Shell {
+  center: Point,
+  radius: i32,
+  vertical: bool,
+  layer: i32,
+  ring: Iterator,
+  pole: Option<Point>,
+}
+
+impl Shell {
+  /// Create a new shell iterator
+  pub fn new<T: Borrow<Point>>(center: &T, radius: i32, vertical: bool) -> Shell {
+    let center: Point = *center.borrow();
+
+    Shell {
+      center,
+      radius,
+      vertical,
+      layer: 0,
+      ring: Iterator::new(&center, radius),
+      pole: match radius {
+        0 => Some(center),
+        _ => None,
+      },
+    }
+  }
+
+  /// Return the height offset of a layer
+  ///
+  /// Layers alternate up and down: 0, 1, -1, 2, -2 and so on.
+  fn offset(layer: i32) -> i32 {
+    match layer % 2 {
+      1 => (layer + 1) / 2,
+      _ => -layer / 2,
+    }
+  }
+
+  /// Move to the next layer, returning false if there are no more layers
+  fn advance(&mut self) -> bool {
+    self.layer += 1;
+
+    if !self.vertical || self.layer > self.radius * 2 {
+      return false;
+    }
+
+    let offset = Self::offset(self.layer);
+    let center: Point = self.center.travel(&Direction::Up, offset);
+    let radius = self.radius - offset.abs();
+
+    self.ring = Iterator::new(&center, radius);
+    self.pole = match radius {
+      0 => Some(center),
+      _ => None,
+    };
+
+    true
+  }
+}
+
+impl iter::Iterator for Shell {
+  type Item = Point;
+
+  /// Find the next point in the shell
+  fn next(&mut self) -> Option<Point> {
+    if self.radius < 0 {
+      return None;
+    }
+
+    loop {
+      if let Some(point) = self.ring.next() {
+        return Some(point);
+      }
+
+      if let Some(point) = self.pole.take() {
+        return Some(point);
+      }
+
+      if !self.advance() {
+        return None;
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashSet;
+  use traits::distance::Distance;
+  use traits::ring::Base;
+
+  const CENTER: &Point = &Point(1, 2, 5);
+
+  #[test]
+  fn next_returns_shell() {
+    let points: Vec<Point> = Shell::new(CENTER, 1, true).collect();
+    let mut expected: Vec<Point> = Iterator::new(CENTER, 1).collect();
+
+    expected.push(Point(1, 2, 6));
+    expected.push(Point(1, 2, 4));
+
+    assert!(expected == points);
+  }
+
+  #[test]
+  fn next_returns_unique_points() {
+    for radius in 0..4 {
+      let points: Vec<Point> = Shell::new(CENTER, radius, true).collect();
+      let set: HashSet<Point> = points.iter().cloned().collect();
+
+      assert!(set.len() == points.len());
+      assert!(points.iter().all(|point| CENTER.distance(point) == radius));
+    }
+
+    assert!(26 == Shell::new(CENTER, 2, true).count());
+  }
+
+  #[test]
+  fn next_not_vertical() {
+    let points: HashSet<Point> = Shell::new(CENTER, 2, false).collect();
+
+    assert!(points == CENTER.base_ring(2));
+  }
+
+  #[test]
+  fn next_radius_zero() {
+    let points: Vec<Point> = Shell::new(CENTER, 0, true).collect();
+
+    assert!(points == vec![ *CENTER ]);
+  }
+}
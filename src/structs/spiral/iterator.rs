@@ -0,0 +1,100 @@
+use std::borrow::Borrow;
+use std::iter;
+
+use structs::Point;
+use structs::ring::Shell;
+
+/// A spiral iterator returns points in shells moving outward from a point
+///
+/// The center is returned first, followed by each shell in order of its
+/// radius. Each shell is returned in the order of a shell iterator, so the
+/// order is the same every time.
+pub struct Iterator {
+  center: Point,
+  range: i32,
+  radius: i32,
+  vertical: bool,
+  shell: Shell,
+}
+
+impl Iterator {
+  /// Create a new spiral iterator
+  ///
+  /// A spiral which is not vertical only has points at the height of the
+  /// center.
+  pub fn new<T: Borrow<Point>>(center: &T, range: i32, vertical: bool) -> Iterator {
+    let center: Point = *center.borrow();
+
+    Iterator {
+      center,
+      range,
+      radius: 0,
+      vertical,
+      shell: Shell::new(&center, 0, vertical),
+    }
+  }
+}
+
+impl iter::Iterator for Iterator {
+  type Item = Point;
+
+  /// Find the next point in the spiral
+  fn next(&mut self) -> Option<Point> {
+    while self.radius <= self.range {
+      if let Some(point) = self.shell.next() {
+        return Some(point);
+      }
+
+      self.radius += 1;
+      self.shell = Shell::new(&self.center, self.radius, self.vertical);
+    }
+
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use traits::distance::{Base, Distance};
+
+  const CENTER: &Point = &Point(1, 2, 5);
+
+  #[test]
+  fn next_returns_spiral() {
+    let mut iter = Iterator::new(CENTER, 1, false);
+
+    assert!(*CENTER == iter.next().unwrap());
+    assert!(Point(1, 1, 5) == iter.next().unwrap());
+    assert!(Point(2, 1, 5) == iter.next().unwrap());
+    assert!(Point(2, 2, 5) == iter.next().unwrap());
+    assert!(Point(1, 3, 5) == iter.next().unwrap());
+    assert!(Point(0, 3, 5) == iter.next().unwrap());
+    assert!(Point(0, 2, 5) == iter.next().unwrap());
+    assert!(iter.next().is_none());
+  }
+
+  #[test]
+  fn next_moves_outward() {
+    let points: Vec<Point> = Iterator::new(CENTER, 3, false).collect();
+    let distances: Vec<i32> = points.iter().map(|p| CENTER.base_distance(p)).collect();
+
+    assert!(points.len() == 37);
+    assert!(distances.windows(2).all(|pair| pair[0] <= pair[1]));
+  }
+
+  #[test]
+  fn next_vertical_moves_outward() {
+    let points: Vec<Point> = Iterator::new(CENTER, 3, true).collect();
+    let distances: Vec<i32> = points.iter().map(|p| CENTER.distance(p)).collect();
+
+    assert!(points.len() == 91);
+    assert!(distances.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(*distances.last().unwrap() == 3);
+  }
+
+  #[test]
+  fn next_negative_range() {
+    assert!(Iterator::new(CENTER, -1, true).next().is_none());
+  }
+}
@@ -0,0 +1,400 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use enums::{Axis, Direction};
+use structs::{Edge, Point, Prism};
+use traits::reflect::Reflect;
+use traits::rotate::Rotate;
+use traits::travel::Travel;
+
+/// A transform moves points without changing the distances between them
+///
+/// A transform first reflects a point across the Q axis if necessary, then
+/// rotates it around the origin, then flips its height if necessary, and
+/// finally translates it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Transform {
+  rotation: i32,
+  reflection: bool,
+  flip: bool,
+  translation: Point,
+}
+
+impl Transform {
+
+  /// Create a transform which does nothing
+  pub fn identity() -> Transform {
+    Transform {
+      rotation: 0,
+      reflection: false,
+      flip: false,
+      translation: Point(0, 0, 0),
+    }
+  }
+
+  /// Create a transform which moves points by the provided offset
+  pub fn translation<T: Borrow<Point>>(offset: &T) -> Transform {
+    Transform { translation: *offset.borrow(), ..Transform::identity() }
+  }
+
+  /// Create a transform which rotates points around a provided center
+  ///
+  /// Positive rotations are clockwise, as with `Rotate::rotate`.
+  pub fn rotation<T: Borrow<Point>>(center: &T, times: i32) -> Transform {
+    let rotation = Transform {
+      rotation: times.rem_euclid(6),
+      ..Transform::identity()
+    };
+
+    Self::around(center, &rotation)
+  }
+
+  /// Create a transform which reflects points across an axis through a center
+  pub fn reflection<T: Borrow<Point>>(center: &T, axis: &Axis) -> Transform {
+    let rotation = match *axis {
+      Axis::Q     => 0,
+      Axis::AntiR => 1,
+      Axis::S     => 2,
+      Axis::AntiQ => 3,
+      Axis::R     => 4,
+      Axis::AntiS => 5,
+    };
+
+    let reflection = Transform {
+      rotation,
+      reflection: true,
+      ..Transform::identity()
+    };
+
+    Self::around(center, &reflection)
+  }
+
+  /// Create a transform which flips heights around the height of a center
+  pub fn flip<T: Borrow<Point>>(center: &T) -> Transform {
+    let flip = Transform { flip: true, ..Transform::identity() };
+
+    Self::around(center, &flip)
+  }
+
+  /// Create a transform which applies this transform and then another
+  pub fn then(&self, other: &Transform) -> Transform {
+    let rotation = match other.reflection {
+      true => other.rotation - self.rotation,
+      false => other.rotation + self.rotation,
+    };
+
+    Transform {
+      rotation: rotation.rem_euclid(6),
+      reflection: self.reflection != other.reflection,
+      flip: self.flip != other.flip,
+      translation: other.apply(&self.translation),
+    }
+  }
+
+  /// Create a transform which undoes this transform
+  pub fn inverse(&self) -> Transform {
+    let linear = Transform {
+      rotation: match self.reflection {
+        true => self.rotation,
+        false => (-self.rotation).rem_euclid(6),
+      },
+      translation: Point(0, 0, 0),
+      ..*self
+    };
+
+    let Point(q, r, t) = linear.apply(&self.translation);
+
+    Transform { translation: Point(-q, -r, -t), ..linear }
+  }
+
+  /// Transform a point
+  pub fn apply<T: Borrow<Point>>(&self, point: &T) -> Point {
+    let point: &Point = point.borrow();
+    let origin: Point = Point(0, 0, 0);
+
+    let reflected: Point = match self.reflection {
+      true => point.reflect(&origin, &Axis::Q),
+      false => *point,
+    };
+
+    let Point(q, r, t) = reflected.rotate(&origin, self.rotation);
+
+    let flipped: Point = match self.flip {
+      true => Point(q, r, -t),
+      false => Point(q, r, t),
+    };
+
+    &flipped + &self.translation
+  }
+
+  /// Transform a direction
+  pub fn apply_direction(&self, direction: &Direction) -> Direction {
+    let origin: Point = self.apply(&Point(0, 0, 0));
+    let moved: Point = self.apply(&Point(0, 0, 0).travel(direction, 1));
+
+    Direction::try_from((&origin, &moved)).unwrap()
+  }
+
+  /// Transform an edge, keeping it in the form a prism would store it
+  pub fn apply_edge(&self, edge: &Edge) -> Edge {
+    let &Edge(point, direction) = edge;
+
+    Edge::from((&self.apply(&point), &self.apply_direction(&direction)))
+  }
+
+  /// Transform a prism
+  ///
+  /// Walls which end up on a side the prism does not store are moved to the
+  /// neighbor which does, so the result may contain more than one prism. The
+  /// first prism is always the one at the transformed point.
+  pub fn apply_prism<T: Borrow<Prism>>(&self, prism: &T) -> Vec<Prism> {
+    let &Prism(point, _, _, _, _) = prism.borrow();
+    let moved: Point = self.apply(&point);
+
+    let mut map: HashMap<Point, &Prism> = HashMap::new();
+
+    map.insert(point, prism.borrow());
+
+    let mut result: HashMap<Point, Prism> = self.apply_map(&map);
+    let mut prisms: Vec<Prism> = vec![ result.remove(&moved).unwrap() ];
+
+    prisms.extend(result.into_values());
+
+    prisms
+  }
+
+  /// Transform every prism on a map
+  ///
+  /// Walls which end up on a side a prism does not store are moved to the
+  /// neighbor which does, creating that neighbor if it is not on the map.
+  pub fn apply_map<T: Borrow<Prism>>(
+    &self,
+    map: &HashMap<Point, T>,
+  ) -> HashMap<Point, Prism> {
+    let mut result: HashMap<Point, Prism> = HashMap::new();
+
+    for prism in map.values() {
+      let &Prism(point, _, _, _, _) = prism.borrow();
+      let moved: Point = self.apply(&point);
+
+      result.entry(moved).or_insert(Prism(moved, 0, 0, 0, 0));
+    }
+
+    for prism in map.values() {
+      let prism: &Prism = prism.borrow();
+      let &Prism(point, _, _, _, _) = prism;
+
+      for direction in prism.walls() {
+        let edge: Edge = self.apply_edge(&Edge(point, direction));
+        let Edge(owner, side) = edge;
+
+        result
+          .entry(owner)
+          .or_insert(Prism(owner, 0, 0, 0, 0))
+          .set_wall(&side, prism.wall(&direction));
+      }
+    }
+
+    result
+  }
+
+  /// Conjugate a transform so it happens around a center instead of the origin
+  fn around<T: Borrow<Point>>(center: &T, transform: &Transform) -> Transform {
+    let &Point(q, r, t) = center.borrow();
+
+    Transform::translation(&Point(-q, -r, -t))
+      .then(transform)
+      .then(&Transform::translation(center))
+  }
+
+}
+
+impl Default for Transform {
+  fn default() -> Transform {
+    Transform::identity()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+  use traits::IsPointMap;
+
+  const CENTER: &Point = &Point(1, 2, 5);
+
+  fn points() -> Vec<Point> {
+    let mut points: Vec<Point> = Vec::new();
+
+    for q in -2..3 {
+      for r in -2..3 {
+        for t in -1..2 {
+          points.push(Point(q, r, t));
+        }
+      }
+    }
+
+    points
+  }
+
+  fn transforms() -> Vec<Transform> {
+    vec![
+      Transform::translation(&Point(3, -1, 2)),
+      Transform::rotation(CENTER, 1),
+      Transform::rotation(CENTER, -2),
+      Transform::reflection(CENTER, &Axis::Q),
+      Transform::reflection(CENTER, &Axis::R),
+      Transform::reflection(&Point(0, 0, 0), &Axis::S),
+      Transform::reflection(CENTER, &Axis::AntiR),
+      Transform::flip(CENTER),
+    ]
+  }
+
+  #[test]
+  fn translation() {
+    let transform: Transform = Transform::translation(&Point(3, -1, 2));
+
+    assert!(Point(4, 1, 7) == transform.apply(CENTER));
+  }
+
+  #[test]
+  fn rotation() {
+    let transform: Transform = Transform::rotation(&Point(1, 1, 5), 2);
+
+    assert!(Point(0, 1, 5) == transform.apply(CENTER));
+    assert!(Point(0, 1, 5) == CENTER.rotate(&Point(1, 1, 5), 2));
+  }
+
+  #[test]
+  fn reflection() {
+    let origin: Point = Point(0, 0, 0);
+    let point: Point = Point(2, 1, 4);
+
+    let q: Transform = Transform::reflection(&origin, &Axis::Q);
+    let r: Transform = Transform::reflection(&origin, &Axis::R);
+    let s: Transform = Transform::reflection(&origin, &Axis::S);
+
+    assert!(Point(2, -3, 4) == q.apply(&point));
+    assert!(Point(-3, 1, 4) == r.apply(&point));
+    assert!(Point(1, 2, 4) == s.apply(&point));
+    assert!(Point(0, 3, 5) == Transform::reflection(CENTER, &Axis::S).apply(&Point(2, 1, 5)));
+  }
+
+  #[test]
+  fn reflection_matches_reflect() {
+    for axis in &Axis::to_vec() {
+      let transform: Transform = Transform::reflection(CENTER, axis);
+
+      for point in &points() {
+        assert!(point.reflect(CENTER, axis) == transform.apply(point));
+      }
+    }
+  }
+
+  #[test]
+  fn flip() {
+    let transform: Transform = Transform::flip(CENTER);
+
+    assert!(Point(0, 0, 7) == transform.apply(&Point(0, 0, 3)));
+  }
+
+  #[test]
+  fn then() {
+    for first in &transforms() {
+      for second in &transforms() {
+        let composed: Transform = first.then(second);
+
+        for point in &points() {
+          assert!(second.apply(&first.apply(point)) == composed.apply(point));
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn inverse() {
+    for transform in &transforms() {
+      let composed: Transform = transform.then(&transform.then(transform));
+
+      assert!(Transform::identity() == transform.then(&transform.inverse()));
+      assert!(Transform::identity() == composed.inverse().then(&composed));
+    }
+  }
+
+  #[test]
+  fn apply_direction() {
+    let rotation: Transform = Transform::rotation(CENTER, 1);
+    let reflection: Transform = Transform::reflection(CENTER, &Axis::R);
+    let flip: Transform = Transform::flip(CENTER);
+
+    assert!(Southeast == rotation.apply_direction(&East));
+    assert!(Up        == rotation.apply_direction(&Up));
+    assert!(West      == reflection.apply_direction(&East));
+    assert!(Southwest == reflection.apply_direction(&Southeast));
+    assert!(Down      == flip.apply_direction(&Up));
+  }
+
+  #[test]
+  fn apply_edge() {
+    let rotation: Transform = Transform::rotation(&Point(0, 0, 0), 3);
+    let flip: Transform = Transform::flip(&Point(0, 0, 0));
+
+    assert!(Edge(Point(-2, 0, 0), East) == rotation.apply_edge(&Edge(Point(1, 0, 0), East)));
+    assert!(Edge(Point(1, 0, 1), Down)  == flip.apply_edge(&Edge(Point(1, 0, 0), Down)));
+  }
+
+  #[test]
+  fn apply_prism() {
+    let transform: Transform = Transform::rotation(CENTER, 3);
+    let prisms: Vec<Prism> = transform.apply_prism(&Prism(*CENTER, 1, 0, 2, 3));
+
+    let Prism(point, e, se, sw, d) = prisms[0];
+
+    assert!(*CENTER == point);
+    assert!((0, 0, 0, 3) == (e, se, sw, d));
+    assert!(prisms.len() == 3);
+
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    for prism in prisms {
+      map.insert_walled_point(prism);
+    }
+
+    assert!(1 == map.get(&Point(0, 2, 5)).unwrap().wall(&East));
+    assert!(2 == map.get(&Point(2, 1, 5)).unwrap().wall(&Southwest));
+  }
+
+  #[test]
+  fn apply_map() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert_walled_point(Prism(*CENTER, 1, 0, 0, 0));
+    map.insert_walled_point(Prism(Point(1, 3, 5), 0, 0, 0, 1));
+
+    let transforms: Vec<Transform> = vec![
+      Transform::rotation(CENTER, 2),
+      Transform::reflection(CENTER, &Axis::Q),
+      Transform::flip(CENTER).then(&Transform::translation(&Point(2, 0, 0))),
+    ];
+
+    for transform in &transforms {
+      let result: HashMap<Point, Prism> = transform.apply_map(&map);
+
+      for point in &points() {
+        let point: Point = point + CENTER;
+
+        for direction in &Direction::to_vec() {
+          let neighbor: Point = point.travel(direction, 1);
+          let moved: Point = transform.apply(&point);
+          let moved_neighbor: Point = transform.apply(&neighbor);
+
+          assert!(
+            map.has_wall_between(&point, &neighbor) ==
+            result.has_wall_between(&moved, &moved_neighbor)
+          );
+        }
+      }
+    }
+  }
+}
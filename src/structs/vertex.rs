@@ -0,0 +1,139 @@
+use enums::{Corner, Direction};
+use enums::Direction::*;
+use structs::{Edge, PixelPoint, Point};
+use traits::travel::Travel;
+
+/// A vertex is a corner where three points meet
+///
+/// Each point owns its north and south corners. The other four corners of a
+/// point are owned by its neighbors.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Vertex(pub Point, pub Corner);
+
+impl Vertex {
+
+  /// Return the three points which touch the vertex
+  pub fn points(&self) -> Vec<Point> {
+    let &Vertex(point, corner) = self;
+    let (left, right) = Self::sides(&corner);
+
+    vec![ point, point.travel(&left, 1), point.travel(&right, 1) ]
+  }
+
+  /// Return the three edges which meet at the vertex
+  ///
+  /// Each edge leads to the vertex at the same index of the adjacent vertices.
+  pub fn edges(&self) -> Vec<Edge> {
+    let &Vertex(point, corner) = self;
+    let (left, right) = Self::sides(&corner);
+    let across: Point = point.travel(&left, 1);
+
+    vec![
+      Edge::from((&point, &left)),
+      Edge::from((&point, &right)),
+      Edge::from((&across, &East)),
+    ]
+  }
+
+  /// Return the three vertices which share an edge with the vertex
+  pub fn adjacent(&self) -> Vec<Vertex> {
+    let &Vertex(point, corner) = self;
+    let (left, right) = Self::sides(&corner);
+    let opposite: Corner = corner.opposite();
+
+    vec![
+      Vertex(point.travel(&left, 1), opposite),
+      Vertex(point.travel(&right, 1), opposite),
+      Vertex(point.travel(&left, 1).travel(&right, 1), opposite),
+    ]
+  }
+
+  /// Return the directions of the other two points touching a corner
+  fn sides(corner: &Corner) -> (Direction, Direction) {
+    match *corner {
+      Corner::North => (Northwest, Northeast),
+      Corner::South => (Southwest, Southeast),
+    }
+  }
+
+}
+
+/// Conveniently convert a vertex into a pixel point
+impl From<Vertex> for PixelPoint {
+  fn from(vertex: Vertex) -> PixelPoint {
+    let Vertex(point, corner) = vertex;
+    let center: PixelPoint = Point::<f32>::from(point).into();
+
+    let offset: PixelPoint = match corner {
+      Corner::North => PixelPoint(0f32, -1f32),
+      Corner::South => PixelPoint(0f32, 1f32),
+    };
+
+    &center + &offset
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const NORTH: &Vertex = &Vertex(Point(1, 2, 5), Corner::North);
+  const SOUTH: &Vertex = &Vertex(Point(1, 2, 5), Corner::South);
+
+  #[test]
+  fn points() {
+    let north: Vec<Point> = NORTH.points();
+    let south: Vec<Point> = SOUTH.points();
+
+    assert!(north == vec![ Point(1, 2, 5), Point(1, 1, 5), Point(2, 1, 5) ]);
+    assert!(south == vec![ Point(1, 2, 5), Point(0, 3, 5), Point(1, 3, 5) ]);
+  }
+
+  #[test]
+  fn edges() {
+    let edges: Vec<Edge> = NORTH.edges();
+
+    assert!(Edge(Point(1, 1, 5), Southeast) == edges[0]);
+    assert!(Edge(Point(2, 1, 5), Southwest) == edges[1]);
+    assert!(Edge(Point(1, 1, 5), East)      == edges[2]);
+    assert!(edges.len() == 3);
+  }
+
+  #[test]
+  fn adjacent() {
+    let adjacent: Vec<Vertex> = SOUTH.adjacent();
+
+    assert!(Vertex(Point(0, 3, 5), Corner::North) == adjacent[0]);
+    assert!(Vertex(Point(1, 3, 5), Corner::North) == adjacent[1]);
+    assert!(Vertex(Point(0, 4, 5), Corner::North) == adjacent[2]);
+    assert!(adjacent.len() == 3);
+
+    for vertex in &adjacent {
+      assert!(vertex.adjacent().contains(SOUTH));
+    }
+  }
+
+  #[test]
+  fn adjacent_shares_edge() {
+    let edges: Vec<Edge> = NORTH.edges();
+
+    for (index, vertex) in NORTH.adjacent().iter().enumerate() {
+      assert!(vertex.edges().contains(&edges[index]));
+    }
+  }
+
+  #[test]
+  fn from_vertex() {
+    let PixelPoint(x, y) = PixelPoint::from(*NORTH);
+
+    assert!(3f32.sqrt() * 2f32 == x);
+    assert!(2f32 == y);
+
+    for point in NORTH.points() {
+      let center: PixelPoint = Point::<f32>::from(point).into();
+      let PixelPoint(dx, dy) = &PixelPoint(x, y) - &center;
+
+      assert!(((dx * dx + dy * dy).sqrt() - 1f32).abs() < 1e-5);
+    }
+  }
+}
@@ -0,0 +1,174 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+use enums::Direction;
+use enums::Direction::*;
+use structs::{Point, Prism};
+use traits::IsPointMap;
+use traits::travel::Travel;
+
+/// A map of walled points which records every point whose walls change
+///
+/// Each change moves the map to a new revision. Anything computed from the
+/// map can remember the revision it was computed at, and later ask whether
+/// the changes since then touch the points it depends on. The log is never
+/// trimmed, so revisions stay comparable for the life of the map.
+#[derive(Clone, Debug, Default)]
+pub struct WallMap {
+  map: HashMap<Point, Prism>,
+  changes: Vec<Point>,
+}
+
+impl WallMap {
+
+  /// Create a map without any walled points
+  pub fn new() -> WallMap {
+    WallMap::default()
+  }
+
+  /// Get the walled points of the map
+  pub fn map(&self) -> &HashMap<Point, Prism> {
+    &self.map
+  }
+
+  /// Get the current revision, which counts the changes made so far
+  pub fn revision(&self) -> usize {
+    self.changes.len()
+  }
+
+  /// Find the points whose walls changed after a revision
+  pub fn changes_since(&self, revision: usize) -> HashSet<Point> {
+    self.changes.iter().skip(revision).cloned().collect()
+  }
+
+  /// Return whether changes after a revision touch any of the provided points
+  ///
+  /// A change touches the changed point and every neighbor it stores a wall
+  /// against, since a wall between two points changes what can be reached
+  /// from either of them. Floods and distance fields computed at the revision
+  /// are still correct if none of their points are touched. Paths are not,
+  /// since removing a wall elsewhere can open a shorter route, so check the
+  /// flood or range a path was searched in instead.
+  pub fn is_touched<T, U>(&self, revision: usize, points: U) -> bool
+    where T: Borrow<Point>, U: IntoIterator<Item = T>
+  {
+    let touched: HashSet<Point> = self.touched_since(revision);
+
+    points.into_iter().any(|point| touched.contains(point.borrow()))
+  }
+
+  /// Remove a walled point, recording the change if it was present
+  pub fn remove_walled_point(&mut self, point: &Point) -> Option<Prism> {
+    let removed: Option<Prism> = self.map.remove(point);
+
+    if removed.is_some() {
+      self.changes.push(*point);
+    }
+
+    removed
+  }
+
+  /// Find every point touched by changes after a revision
+  pub fn touched_since(&self, revision: usize) -> HashSet<Point> {
+    let mut touched: HashSet<Point> = HashSet::new();
+
+    for point in self.changes_since(revision) {
+      touched.insert(point);
+
+      for direction in &[ East, Southeast, Southwest, Down ] {
+        touched.insert(point.travel(direction, 1));
+      }
+    }
+
+    touched
+  }
+
+}
+
+impl From<HashMap<Point, Prism>> for WallMap {
+  fn from(map: HashMap<Point, Prism>) -> WallMap {
+    WallMap { map, changes: Vec::new() }
+  }
+}
+
+impl IsPointMap<Point, Prism> for WallMap {
+
+  /// Check for a wall on the map
+  fn has_wall(&self, point: &Point, direction: &Direction) -> bool {
+    self.map.has_wall(point, direction)
+  }
+
+  /// Check for a wall between two points on the map
+  fn has_wall_between(&self, p0: &Point, p1: &Point) -> bool {
+    self.map.has_wall_between(p0, p1)
+  }
+
+  /// Insert a new walled point, recording the change
+  fn insert_walled_point(&mut self, prism: Prism) -> Option<Prism> {
+    let Prism(point, _, _, _, _) = prism;
+
+    self.changes.push(point);
+    self.map.insert_walled_point(prism)
+  }
+
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use traits::range::BaseFlood;
+
+  #[test]
+  fn revision() {
+    let mut walls: WallMap = WallMap::new();
+
+    assert!(walls.revision() == 0);
+
+    walls.insert_walled_point(Prism(Point(1, 2, 5), 1, 0, 0, 0));
+    walls.insert_walled_point(Prism(Point(2, 2, 5), 0, 1, 0, 0));
+
+    assert!(walls.revision() == 2);
+    assert!(walls.has_wall(&Point(1, 2, 5), &East));
+
+    walls.remove_walled_point(&Point(1, 2, 5));
+    walls.remove_walled_point(&Point(1, 2, 5));
+
+    assert!(walls.revision() == 3);
+    assert!(!walls.has_wall(&Point(1, 2, 5), &East));
+    assert!(walls.changes_since(1) == vec![ Point(2, 2, 5), Point(1, 2, 5) ].into_iter().collect());
+  }
+
+  #[test]
+  fn touched_since() {
+    let mut walls: WallMap = WallMap::new();
+
+    walls.insert_walled_point(Prism(Point(1, 2, 5), 1, 0, 0, 0));
+
+    let touched: HashSet<Point> = walls.touched_since(0);
+
+    assert!(touched.len() == 5);
+    assert!(touched.contains(&Point(2, 2, 5)));
+    assert!(touched.contains(&Point(1, 2, 4)));
+    assert!(!touched.contains(&Point(0, 2, 5)));
+    assert!(walls.touched_since(1).is_empty());
+  }
+
+  #[test]
+  fn is_touched() {
+    let mut walls: WallMap = WallMap::new();
+    let start: Point = Point(0, 0, 0);
+
+    let flood: HashSet<Point> = start.base_flood(2, walls.map());
+    let revision: usize = walls.revision();
+
+    walls.insert_walled_point(Prism(Point(9, 0, 0), 1, 0, 0, 0));
+
+    assert!(!walls.is_touched(revision, &flood));
+
+    walls.insert_walled_point(Prism(Point(2, -3, 0), 0, 0, 1, 0));
+
+    assert!(walls.is_touched(revision, &flood));
+    assert!(walls.is_touched(revision, vec![ Point(1, -2, 0) ]));
+    assert!(!walls.is_touched(walls.revision(), &flood));
+  }
+}
@@ -0,0 +1,108 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use enums::Direction;
+use structs::Point;
+use traits::range::Base as BaseRange;
+use traits::rotate::Rotate;
+
+/// Trait wrapping base cone implementation
+pub trait Base: Borrow<Point> {
+  /// Find the points at the same height within range inside a cone
+  ///
+  /// The cone covers the sixth of the plane around each direction from the
+  /// first to the last, moving clockwise. Provide the same direction twice for
+  /// a cone facing that direction. Points on the edge of the cone are inside
+  /// it. Directions which change height describe an empty cone.
+  fn base_cone(
+    &self,
+    first: &Direction,
+    last: &Direction,
+    range: i32,
+  ) -> HashSet<Point>;
+}
+
+impl<T> Base for T where T: Borrow<Point> {
+  fn base_cone(
+    &self,
+    first: &Direction,
+    last: &Direction,
+    range: i32,
+  ) -> HashSet<Point> {
+    if first.is_vertical() || last.is_vertical() {
+      return HashSet::new();
+    }
+
+    let origin: Point = Point(0, 0, 0);
+    let start = first.index() as i32;
+    let turns = (last.index() as i32 - start).rem_euclid(6);
+
+    let inside = |point: &Point| {
+      let relative: Point = point - self.borrow();
+
+      (start..start + turns + 1).any(|index| {
+        let rotated: Point = relative.rotate(&origin, -index);
+
+        rotated.q() >= rotated.r() && *rotated.r() >= rotated.s()
+      })
+    };
+
+    self.base_range_iter(range).filter(inside).collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+
+  #[test]
+  fn base_cone() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.base_cone(&East, &East, 2);
+
+    assert!(set.contains(&Point(1, 2, 5)));
+    assert!(set.contains(&Point(2, 2, 5)));
+    assert!(set.contains(&Point(3, 2, 5)));
+    assert!(set.contains(&Point(3, 1, 5)));
+    assert!(set.contains(&Point(2, 3, 5)));
+    assert!(set.len() == 5);
+  }
+
+  #[test]
+  fn base_cone_bounded() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.base_cone(&Southwest, &West, 1);
+
+    assert!(set.contains(&Point(1, 2, 5)));
+    assert!(set.contains(&Point(0, 3, 5)));
+    assert!(set.contains(&Point(0, 2, 5)));
+    assert!(set.len() == 3);
+  }
+
+  #[test]
+  fn base_cone_wraps() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.base_cone(&Northeast, &Southeast, 3);
+
+    assert!(set == point.base_cone(&Northeast, &Northeast, 3)
+      .union(&point.base_cone(&East, &Southeast, 3))
+      .cloned()
+      .collect());
+  }
+
+  #[test]
+  fn base_cone_full() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.base_cone(&East, &Northeast, 3);
+
+    assert!(set == point.base_range(3));
+  }
+
+  #[test]
+  fn base_cone_vertical() {
+    let point: Point = Point(1, 2, 5);
+
+    assert!(point.base_cone(&Up, &East, 3).is_empty());
+  }
+}
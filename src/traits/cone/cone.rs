@@ -0,0 +1,66 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use enums::Direction;
+use structs::Point;
+use traits::cone::Base;
+use traits::travel::Travel;
+
+/// Trait wrapping cone implementation
+pub trait Cone: Borrow<Point> {
+  /// Find the points within range inside a cone
+  ///
+  /// The cone is a base cone at every height within range, so the points
+  /// directly above and below are always inside it.
+  fn cone(
+    &self,
+    first: &Direction,
+    last: &Direction,
+    range: i32,
+  ) -> HashSet<Point>;
+}
+
+impl<T> Cone for T where T: Borrow<Point> {
+  fn cone(
+    &self,
+    first: &Direction,
+    last: &Direction,
+    range: i32,
+  ) -> HashSet<Point> {
+    let mut set: HashSet<Point> = self.base_cone(first, last, range);
+
+    for index in 1..range + 1 {
+      let diff = range - index;
+
+      set.extend(self.travel(&Direction::Up, index).base_cone(first, last, diff));
+      set.extend(self.travel(&Direction::Down, index).base_cone(first, last, diff));
+    }
+
+    set
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+
+  #[test]
+  fn cone() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.cone(&East, &East, 2);
+
+    assert!(set.contains(&Point(1, 2, 5)));
+    assert!(set.contains(&Point(2, 2, 5)));
+    assert!(set.contains(&Point(3, 2, 5)));
+    assert!(set.contains(&Point(3, 1, 5)));
+    assert!(set.contains(&Point(2, 3, 5)));
+    assert!(set.contains(&Point(1, 2, 6)));
+    assert!(set.contains(&Point(2, 2, 6)));
+    assert!(set.contains(&Point(1, 2, 7)));
+    assert!(set.contains(&Point(1, 2, 4)));
+    assert!(set.contains(&Point(2, 2, 4)));
+    assert!(set.contains(&Point(1, 2, 3)));
+    assert!(set.len() == 11);
+  }
+}
@@ -0,0 +1,5 @@
+mod base;
+mod cone;
+
+pub use self::base::Base;
+pub use self::cone::Cone;
@@ -1,8 +1,10 @@
 use std::borrow::Borrow;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 
 use enums::Direction;
-use structs::{Point, Prism};
+use structs::{DirectionSet, Point, Prism};
+use traits::travel::Travel;
 
 pub trait IsPointMap<T, U> where T: Borrow<Point>, U: Borrow<Prism> {
 
@@ -10,8 +12,18 @@ pub trait IsPointMap<T, U> where T: Borrow<Point>, U: Borrow<Prism> {
   fn has_wall(&self, &T, &Direction) -> bool;
 
   /// Check for a wall between two points on the map
+  ///
+  /// Points which are not neighbors never share a wall.
   fn has_wall_between(&self, &T, &T) -> bool;
 
+  /// Find the directions of the neighbors reachable without crossing a wall
+  fn reachable_directions(&self, point: &T) -> DirectionSet where T: From<Point> {
+    Direction::to_vec()
+      .into_iter()
+      .filter(|dir| !self.has_wall_between(point, &point.borrow().travel(dir, 1).into()))
+      .collect()
+  }
+
   /// Insert a new walled point
   fn insert_walled_point(&mut self, U) -> Option<U>;
 
@@ -33,11 +45,11 @@ impl<U: Borrow<Prism>> IsPointMap<Point, U> for HashMap<Point, U> {
     p0: &Point,
     p1: &Point,
   ) -> bool {
-    if p0 == p1 {
-      return false;
-    }
+    let dir: Direction = match Direction::try_from((p0, p1)) {
+      Ok(dir) => dir,
+      Err(_) => return false,
+    };
 
-    let dir: Direction = (p0, p1).into();
     let result = self.has_wall(p0, &dir) || self.has_wall(p1, &dir.opposite());
 
     result
@@ -91,6 +103,41 @@ mod tests {
     assert!(map.has_wall_between(&p1, &p0));
   }
 
+  #[test]
+  fn has_wall_between_not_adjacent() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    let p0: Point = Point(1, 2, 5);
+    let p1: Point = Point(3, 2, 5);
+    let p2: Point = Point(2, 3, 5);
+
+    map.insert(p0, Prism(p0, 1, 1, 1, 1));
+
+    assert!(!map.has_wall_between(&p0, &p0));
+    assert!(!map.has_wall_between(&p0, &p1));
+    assert!(!map.has_wall_between(&p0, &p2));
+  }
+
+  #[test]
+  fn reachable_directions() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    let p0: Point = Point(1, 2, 5);
+    let p1: Point = Point(0, 2, 5);
+    let p2: Point = Point(1, 2, 6);
+
+    map.insert(p0, Prism(p0, 1, 0, 0, 0));
+    map.insert(p1, Prism(p1, 1, 0, 0, 0));
+    map.insert(p2, Prism(p2, 0, 0, 0, 1));
+
+    let set: DirectionSet = map.reachable_directions(&p0);
+
+    assert!(!set.contains(&Direction::East));
+    assert!(!set.contains(&Direction::West));
+    assert!(!set.contains(&Direction::Up));
+    assert!(set.len() == 5);
+  }
+
   #[test]
   fn insert_walled_point() {
     let mut map: HashMap<Point, Prism> = HashMap::new();
@@ -1,8 +1,14 @@
+pub mod cone;
 pub mod distance;
 pub mod line;
+pub mod path;
+pub mod range;
 pub mod ray;
+pub mod reflect;
 pub mod ring;
 pub mod rotate;
+pub mod shape;
+pub mod spiral;
 pub mod travel;
 
 mod is_point_map;
@@ -0,0 +1,59 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use structs::{Point, Prism};
+use traits::distance::Base as BaseDistance;
+use traits::path::GenericPath;
+use traits::range::Base as BaseRange;
+
+/// Trait wrapping base path implementation
+pub trait Base: Borrow<Point> {
+  /// Find a shortest path to a point of the same height within a range
+  ///
+  /// The path includes both ends, and is `None` if the point can not be
+  /// reached within the range without crossing a wall.
+  fn base_path<U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    goal: &U,
+    range: i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>>;
+}
+
+impl<T> Base for T where T: Borrow<Point> {
+  fn base_path<U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    goal: &U,
+    range: i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>> {
+    self.generic_path(
+      goal,
+      range,
+      BaseRange::base_range,
+      BaseDistance::base_distance::<Point>,
+      map,
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use traits::IsPointMap;
+
+  #[test]
+  fn base_path() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert_walled_point(Prism(Point(0, 0, 0), 1, 1, 0, 0));
+
+    let path: Vec<Point> = Point(0, 0, 0).base_path(&Point(2, 0, 0), 3, &map).unwrap();
+
+    assert!(path.len() == 4);
+    assert!(path[0] == Point(0, 0, 0));
+    assert!(path[3] == Point(2, 0, 0));
+    assert!(Point(0, 0, 0).base_path(&Point(2, 0, 0), 2, &map).is_none());
+    assert!(Point(0, 0, 0).base_path(&Point(0, 0, 1), 5, &map).is_none());
+  }
+}
@@ -0,0 +1,120 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use enums::Direction;
+use fns::path::facing_neighbors;
+use fns::search::a_star;
+use structs::{Point, Prism};
+use traits::distance::Base as BaseDistance;
+
+/// Trait wrapping facing path implementation
+pub trait FacingPath: Borrow<Point> {
+  /// Find a cheapest path to a point for a unit which pays to turn
+  ///
+  /// Each state of the path is a point and the facing of the unit there.
+  /// Moving forward costs one and each turn costs the turn cost, as in
+  /// `fns::path::facing_neighbors`. The path includes both ends, and is
+  /// `None` if the facing is vertical, the point is at another height, or the
+  /// point can not be reached with the range as a budget.
+  fn facing_path<U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    facing: &Direction,
+    goal: &U,
+    turn_cost: i32,
+    range: i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<(Point, Direction)>>;
+}
+
+impl<T> FacingPath for T where T: Borrow<Point> {
+  fn facing_path<U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    facing: &Direction,
+    goal: &U,
+    turn_cost: i32,
+    range: i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<(Point, Direction)>> {
+    let start: &Point = self.borrow();
+    let goal: &Point = goal.borrow();
+
+    if facing.is_vertical() || start.2 != goal.2 {
+      return None;
+    }
+
+    let neighbors = |state: &(Point, Direction)| {
+      facing_neighbors(state, turn_cost, map)
+        .into_iter()
+        .filter(|&((point, _), _)| {
+          start.base_distance(&point) + point.base_distance(goal) <= range
+        })
+        .collect()
+    };
+
+    let is_goal = |&(point, _): &(Point, Direction)| point == *goal;
+    let heuristic = |&(point, _): &(Point, Direction)| point.base_distance(goal);
+
+    match a_star((*start, *facing), is_goal, neighbors, heuristic) {
+      Some((path, cost)) if cost <= range => Some(path),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+  use traits::IsPointMap;
+
+  #[test]
+  fn facing_path() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let start: Point = Point(0, 0, 0);
+
+    let path: Vec<(Point, Direction)> = start
+      .facing_path(&East, &Point(3, 0, 0), 2, 10, &map)
+      .unwrap();
+
+    assert!(path.len() == 4);
+    assert!(path.iter().all(|&(_, facing)| facing == East));
+
+    let behind: Vec<(Point, Direction)> = start
+      .facing_path(&East, &Point(-1, 0, 0), 2, 10, &map)
+      .unwrap();
+
+    assert!(behind[0] == (start, East));
+    assert!(behind[behind.len() - 1].0 == Point(-1, 0, 0));
+    assert!(start.facing_path(&East, &Point(-1, 0, 0), 2, 5, &map).is_none());
+  }
+
+  #[test]
+  fn facing_path_height() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let start: Point = Point(0, 0, 0);
+
+    assert!(start.facing_path(&East, &Point(0, 0, 1), 2, 1000, &map).is_none());
+    assert!(start.facing_path(&Up, &Point(1, 0, 0), 2, 1000, &map).is_none());
+    assert!(start.facing_path(&Down, &start, 2, 1000, &map).is_none());
+  }
+
+  #[test]
+  fn facing_path_turn_cost() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert_walled_point(Prism(Point(0, 0, 0), 1, 0, 0, 0));
+
+    let cheap: Vec<(Point, Direction)> = Point(0, 0, 0)
+      .facing_path(&East, &Point(1, 0, 0), 1, 10, &map)
+      .unwrap();
+
+    let dear: Vec<(Point, Direction)> = Point(0, 0, 0)
+      .facing_path(&East, &Point(1, 0, 0), 5, 20, &map)
+      .unwrap();
+
+    assert!(cheap.len() == 6);
+    assert!(cheap[cheap.len() - 1].0 == Point(1, 0, 0));
+    assert!(dear[dear.len() - 1].0 == Point(1, 0, 0));
+    assert!(Point(0, 0, 0).facing_path(&East, &Point(1, 0, 0), 5, 10, &map).is_none());
+  }
+}
@@ -0,0 +1,79 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+use fns::search::bounded_path;
+use structs::{Point, Prism};
+use traits::IsPointMap;
+
+/// Trait wrapping generic path implementation
+pub trait GenericPath: Borrow<Point> {
+  /// Find a shortest path to a point within a range with provided functions
+  ///
+  /// The range function provides the neighbors of a point, and the distance
+  /// function must never overestimate the steps between two points. The path
+  /// includes both ends, and is `None` if the point can not be reached within
+  /// the range.
+  fn generic_path<U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    goal: &U,
+    range: i32,
+    range_fn: fn(&Point, i32) -> HashSet<Point>,
+    distance_fn: fn(&Point, &Point) -> i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>>;
+}
+
+impl<T> GenericPath for T where T: Borrow<Point> {
+  fn generic_path<U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    goal: &U,
+    range: i32,
+    range_fn: fn(&Point, i32) -> HashSet<Point>,
+    distance_fn: fn(&Point, &Point) -> i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>> {
+    let neighbors = |point: &Point| {
+      range_fn(point, 1)
+        .into_iter()
+        .filter(|neighbor| neighbor != point && !map.has_wall_between(point, neighbor))
+        .collect()
+    };
+
+    bounded_path(self.borrow(), goal.borrow(), range, neighbors, distance_fn)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+  use traits::distance::Height;
+  use traits::travel::Travel;
+
+  #[test]
+  fn generic_path() {
+    let start: Point = Point(0, 0, 0);
+    let wall: Point = Point(0, 0, 2);
+
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert(wall, Prism(wall, 0, 0, 0, 1));
+
+    fn range_1d(point: &Point, range: i32) -> HashSet<Point> {
+      let mut set: HashSet<Point> = HashSet::new();
+
+      set.insert(point.travel(&Up, range));
+      set.insert(point.travel(&Down, range));
+
+      set
+    }
+
+    let path: Vec<Point> = start
+      .generic_path(&Point(0, 0, -2), 2, range_1d, Height::height::<Point>, &map)
+      .unwrap();
+
+    assert!(path == vec![ start, Point(0, 0, -1), Point(0, 0, -2) ]);
+    assert!(start.generic_path(&wall, 5, range_1d, Height::height::<Point>, &map).is_none());
+    assert!(start.generic_path(&Point(0, 0, -3), 2, range_1d, Height::height::<Point>, &map).is_none());
+  }
+}
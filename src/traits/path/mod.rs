@@ -0,0 +1,11 @@
+mod base;
+mod facing;
+mod generic;
+mod path;
+mod zone;
+
+pub use self::base::Base;
+pub use self::facing::FacingPath;
+pub use self::generic::GenericPath;
+pub use self::path::Path;
+pub use self::zone::ZonePath;
@@ -0,0 +1,54 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use structs::{Point, Prism};
+use traits::distance::Distance;
+use traits::path::GenericPath;
+use traits::range::Range;
+
+/// Trait wrapping path implementation
+pub trait Path: Borrow<Point> {
+  /// Find a shortest path to a point within a range
+  ///
+  /// The path includes both ends, and is `None` if the point can not be
+  /// reached within the range without crossing a wall.
+  fn path<U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    goal: &U,
+    range: i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>>;
+}
+
+impl<T> Path for T where T: Borrow<Point> {
+  fn path<U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    goal: &U,
+    range: i32,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>> {
+    self.generic_path(goal, range, Range::range, Distance::distance::<Point>, map)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use traits::IsPointMap;
+
+  #[test]
+  fn path() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert_walled_point(Prism(Point(0, 0, 0), 1, 0, 0, 0));
+    map.insert_walled_point(Prism(Point(0, 0, 1), 0, 0, 0, 1));
+
+    let path: Vec<Point> = Point(0, 0, 0).path(&Point(1, 0, 1), 4, &map).unwrap();
+
+    assert!(path.len() == 4);
+    assert!(path[0] == Point(0, 0, 0));
+    assert!(path[3] == Point(1, 0, 1));
+    assert!(Point(0, 0, 0).path(&Point(1, 0, 1), 2, &map).is_none());
+    assert!(Point(0, 0, 0).path(&Point(0, 0, 1), 1, &map).is_none());
+  }
+}
@@ -0,0 +1,75 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+use enums::Zone;
+use fns::search::backtrack;
+use structs::{Point, Prism};
+use traits::range::GenericReach;
+
+/// Trait wrapping zone path implementation
+pub trait ZonePath: Borrow<Point> {
+  /// Find a cheapest path to a point within a range through hostile zones
+  ///
+  /// Neighbors are provided by the range function, walls block movement as
+  /// usual, and entering a controlled point either ends movement or costs
+  /// extra depending on the zone. The path includes both ends, and is `None`
+  /// if the point can not be reached within the range.
+  fn zone_path<U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    goal: &U,
+    range: i32,
+    range_fn: fn(&Point, i32) -> HashSet<Point>,
+    zones: &HashSet<Point>,
+    zone: &Zone,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>>;
+}
+
+impl<T> ZonePath for T where T: Borrow<Point> {
+  fn zone_path<U: Borrow<Point>, V: Borrow<Prism>>(
+    &self,
+    goal: &U,
+    range: i32,
+    range_fn: fn(&Point, i32) -> HashSet<Point>,
+    zones: &HashSet<Point>,
+    zone: &Zone,
+    map: &HashMap<Point, V>,
+  ) -> Option<Vec<Point>> {
+    let start: &Point = self.borrow();
+    let cost_fn = |from: &Point, to: &Point| zone.cost(start, from, to, zones);
+
+    backtrack(&self.generic_reach(range, range_fn, cost_fn, map), goal.borrow())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use traits::range::Base;
+
+  #[test]
+  fn zone_path() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let start: Point = Point(0, 0, 0);
+    let goal: Point = Point(4, 0, 0);
+    let zones: HashSet<Point> = vec![ Point(2, 0, 0), Point(2, -1, 0), Point(1, 1, 0) ]
+      .into_iter()
+      .collect();
+
+    let stop: Vec<Point> = start
+      .zone_path(&goal, 10, Base::base_range, &zones, &Zone::Stop, &map)
+      .unwrap();
+
+    assert!(stop[0] == start);
+    assert!(stop[stop.len() - 1] == goal);
+    assert!(stop[1 .. stop.len() - 1].iter().all(|point| !zones.contains(point)));
+
+    let cost: Vec<Point> = start
+      .zone_path(&goal, 10, Base::base_range, &zones, &Zone::Cost(5), &map)
+      .unwrap();
+
+    assert!(cost.iter().all(|point| !zones.contains(point)));
+    assert!(start.zone_path(&goal, 4, Base::base_range, &zones, &Zone::Cost(5), &map).is_none());
+    assert!(start.zone_path(&Point(2, 0, 0), 4, Base::base_range, &zones, &Zone::Stop, &map).unwrap().len() == 3);
+  }
+}
@@ -1,32 +1,29 @@
 use std::borrow::Borrow;
 use std::collections::HashSet;
-use std::cmp::{max, min};
 
 use structs::Point;
+use structs::range::Iterator;
 
 /// Trait wrapping base range implementation
 pub trait Base: Borrow<Point> {
   /// Find the points at the same height within the provided manhattan distance
   fn base_range(&self, range: i32) -> HashSet<Point>;
+
+  /// Iterate over the points at the same height within the provided manhattan
+  /// distance
+  ///
+  /// Points are returned in lines from west to east, and each line from
+  /// southeast to northwest.
+  fn base_range_iter(&self, range: i32) -> Iterator;
 }
 
 impl<T> Base for T where T: Borrow<Point> {
-  pub fn base_range(&self, range: i32) -> HashSet<Point> {
-    let mut set: HashSet<Point> = HashSet::new();
-
-    for dq in -range..range + 1 {
-      let lower: i32 = max(-range, -dq - range);
-      let upper: i32 = min(range, -dq + range);
-
-      for ds in lower..upper + 1 {
-        let dr: i32 = -dq - ds;
-        let found = self.borrow() + &Point(dq, dr, 0);
-
-        set.insert(found);
-      }
-    }
+  fn base_range(&self, range: i32) -> HashSet<Point> {
+    self.base_range_iter(range).collect()
+  }
 
-    set
+  fn base_range_iter(&self, range: i32) -> Iterator {
+    Iterator::new(self, range, false)
   }
 }
 
@@ -37,7 +34,7 @@ mod tests {
   #[test]
   fn base_range() {
     let point: Point = Point(1, 2, 5);
-    let set: HashSet<Point> = point.base_range(&point, 1);
+    let set: HashSet<Point> = point.base_range(1);
 
     assert!(set.contains(&Point(1, 2, 5)));
     assert!(set.contains(&Point(2, 2, 5)));
@@ -48,4 +45,15 @@ mod tests {
     assert!(set.contains(&Point(2, 1, 5)));
     assert!(set.len() == 7);
   }
+
+  #[test]
+  fn base_range_iter() {
+    let point: Point = Point(1, 2, 5);
+    let vec: Vec<Point> = point.base_range_iter(1).collect();
+
+    assert!(Point(0, 3, 5) == vec[0]);
+    assert!(Point(1, 2, 5) == vec[3]);
+    assert!(Point(2, 1, 5) == vec[6]);
+    assert!(vec.len() == 7);
+  }
 }
@@ -0,0 +1,54 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use enums::Direction;
+use structs::Point;
+use traits::range::Base;
+use traits::travel::Travel;
+
+/// Trait wrapping cylinder implementation
+pub trait Cylinder: Borrow<Point> {
+  /// Find the points within a manhattan distance and a separate height
+  ///
+  /// Every height within the height range has the same base range, so the
+  /// height does not use up any of the manhattan distance.
+  fn cylinder(&self, range: i32, height: i32) -> HashSet<Point>;
+}
+
+impl<T> Cylinder for T where T: Borrow<Point> {
+  fn cylinder(&self, range: i32, height: i32) -> HashSet<Point> {
+    let mut set: HashSet<Point> = HashSet::new();
+
+    for index in -height..height + 1 {
+      set.extend(self.travel(&Direction::Up, index).base_range_iter(range));
+    }
+
+    set
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn cylinder() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.cylinder(1, 1);
+
+    assert!(set.contains(&Point(1, 2, 5)));
+    assert!(set.contains(&Point(2, 2, 5)));
+    assert!(set.contains(&Point(2, 2, 6)));
+    assert!(set.contains(&Point(0, 3, 4)));
+    assert!(set.contains(&Point(1, 1, 6)));
+    assert!(!set.contains(&Point(1, 2, 7)));
+    assert!(set.len() == 21);
+  }
+
+  #[test]
+  fn cylinder_flat() {
+    let point: Point = Point(1, 2, 5);
+
+    assert!(point.cylinder(3, 0) == point.base_range(3));
+  }
+}
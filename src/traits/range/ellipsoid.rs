@@ -0,0 +1,75 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use enums::Direction;
+use structs::Point;
+use traits::distance::Base as BaseDistance;
+use traits::range::Base;
+use traits::travel::Travel;
+
+/// Trait wrapping ellipsoid implementation
+pub trait Ellipsoid: Borrow<Point> {
+  /// Find the points within an ellipsoid with separate width and height
+  ///
+  /// A point is inside the ellipsoid if its manhattan distance ignoring height
+  /// divided by the range and its height divided by the height make a vector
+  /// no longer than one.
+  fn ellipsoid(&self, range: i32, height: i32) -> HashSet<Point>;
+}
+
+impl<T> Ellipsoid for T where T: Borrow<Point> {
+  fn ellipsoid(&self, range: i32, height: i32) -> HashSet<Point> {
+    let mut set: HashSet<Point> = HashSet::new();
+    let limit = range * range * height * height;
+
+    for index in -height..height + 1 {
+      let center: Point = self.travel(&Direction::Up, index);
+      let vertical = index * index * range * range;
+
+      set.extend(center.base_range_iter(range).filter(|point| {
+        let distance = center.base_distance(point);
+
+        distance * distance * height * height + vertical <= limit
+      }));
+    }
+
+    set
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ellipsoid() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.ellipsoid(2, 1);
+
+    assert!(set.contains(&Point(1, 2, 5)));
+    assert!(set.contains(&Point(3, 2, 5)));
+    assert!(set.contains(&Point(1, 2, 6)));
+    assert!(set.contains(&Point(1, 2, 4)));
+    assert!(!set.contains(&Point(2, 2, 6)));
+    assert!(set.len() == 21);
+  }
+
+  #[test]
+  fn ellipsoid_wide() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.ellipsoid(4, 2);
+
+    assert!(set.contains(&Point(4, 2, 6)));
+    assert!(!set.contains(&Point(5, 2, 6)));
+    assert!(set.contains(&Point(1, 2, 7)));
+    assert!(!set.contains(&Point(2, 2, 7)));
+    assert!(set.len() == 137);
+  }
+
+  #[test]
+  fn ellipsoid_flat() {
+    let point: Point = Point(1, 2, 5);
+
+    assert!(point.ellipsoid(3, 0) == point.base_range(3));
+  }
+}
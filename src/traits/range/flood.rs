@@ -2,7 +2,7 @@ use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
 
 use structs::{Point, Prism};
-use traits::range::Range;
+use traits::range::{GenericFlood, Range};
 
 /// Trait wrapping flood implementation
 pub trait Flood: Borrow<Point> {
@@ -21,9 +21,9 @@ impl<T> Flood for T where T: Borrow<Point> {
   fn flood<U: Borrow<Prism>>(
     &self,
     range: i32,
-    map: &Hashmap<Point, U>
+    map: &HashMap<Point, U>,
   ) -> HashSet<Point> {
-    point.flood_generic(range, point::range, map)
+    self.generic_flood(range, Range::range, map)
   }
 }
 
@@ -2,7 +2,7 @@ use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
 
 use structs::{Point, Prism};
-use traits::range::Base;
+use traits::range::{Base, GenericFlood};
 
 /// Trait wrapping base flood implementation
 pub trait BaseFlood: Borrow<Point> {
@@ -14,7 +14,7 @@ pub trait BaseFlood: Borrow<Point> {
     &self,
     range: i32,
     map: &HashMap<Point, U>,
-  ) -> HashSet,Point>;
+  ) -> HashSet<Point>;
 }
 
 impl<T> BaseFlood for T where T: Borrow<Point> {
@@ -23,7 +23,7 @@ impl<T> BaseFlood for T where T: Borrow<Point> {
     range: i32,
     map: &HashMap<Point, U>,
   ) -> HashSet<Point> {
-    range::flood_generic(point, range, point::base_range, map)
+    self.generic_flood(range, Base::base_range, map)
   }
 }
 
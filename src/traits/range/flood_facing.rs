@@ -0,0 +1,60 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use enums::Direction;
+use fns::path::facing_neighbors;
+use fns::search::dijkstra;
+use structs::{Point, Prism};
+
+/// Trait wrapping facing flood implementation
+pub trait FacingFlood: Borrow<Point> {
+  /// Find reachable points and facings for a unit which pays to turn
+  ///
+  /// Each reachable point and facing is mapped to the cheapest cost of
+  /// reaching it, where moving forward costs one and each turn costs the turn
+  /// cost, as in `fns::path::facing_neighbors`. A vertical facing reaches
+  /// only the point itself.
+  fn facing_flood<U: Borrow<Prism>>(
+    &self,
+    facing: &Direction,
+    turn_cost: i32,
+    range: i32,
+    map: &HashMap<Point, U>,
+  ) -> HashMap<(Point, Direction), i32>;
+}
+
+impl<T> FacingFlood for T where T: Borrow<Point> {
+  fn facing_flood<U: Borrow<Prism>>(
+    &self,
+    facing: &Direction,
+    turn_cost: i32,
+    range: i32,
+    map: &HashMap<Point, U>,
+  ) -> HashMap<(Point, Direction), i32> {
+    dijkstra((*self.borrow(), *facing), range, |state| facing_neighbors(state, turn_cost, map))
+      .into_iter()
+      .map(|(state, (cost, _))| (state, cost))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+
+  #[test]
+  fn facing_flood() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let start: Point = Point(1, 2, 5);
+    let flood: HashMap<(Point, Direction), i32> = start.facing_flood(&East, 2, 3, &map);
+
+    assert!(flood[&(start, East)] == 0);
+    assert!(flood[&(Point(4, 2, 5), East)] == 3);
+    assert!(flood[&(start, Southeast)] == 2);
+    assert!(flood[&(Point(1, 3, 5), Southeast)] == 3);
+    assert!(!flood.contains_key(&(start, Southwest)));
+    assert!(!flood.contains_key(&(Point(0, 2, 5), West)));
+    assert!(start.facing_flood(&Up, 2, 3, &map).len() == 1);
+  }
+}
@@ -1,6 +1,7 @@
 use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
 
+use fns::search::flood;
 use structs::{Point, Prism};
 use traits::IsPointMap;
 
@@ -12,7 +13,7 @@ pub trait GenericFlood: Borrow<Point> {
     range: i32,
     range_fn: fn(&Point, i32) -> HashSet<Point>,
     map: &HashMap<Point, U>,
-  ) -> HashSet,Point>;
+  ) -> HashSet<Point>;
 }
 
 impl<T> GenericFlood for T where T: Borrow<Point> {
@@ -22,31 +23,14 @@ impl<T> GenericFlood for T where T: Borrow<Point> {
     range_fn: fn(&Point, i32) -> HashSet<Point>,
     map: &HashMap<Point, U>,
   ) -> HashSet<Point> {
-    let mut visited: HashSet<Point> = HashSet::new();
-    let mut fringes: Vec<Point> = Vec::new();
-    let mut found: Vec<Point> = Vec::new();
-
-    fringes.push(*self.borrow());
-
-    for _ in 0 .. range {
-      for point in &fringes {
-        for neighbor in &range_fn(point, 1) {
-          if visited.contains(neighbor) {
-            continue;
-          } else if !map.has_wall_between(point, neighbor) {
-            found.push(*neighbor);
-          }
-        }
-      }
-
-      visited.extend(fringes);
-      fringes = found;
-      found = Vec::new();
-    }
-
-    visited.extend(fringes);
+    let neighbors = |point: &Point| {
+      range_fn(point, 1)
+        .into_iter()
+        .filter(|neighbor| !map.has_wall_between(point, neighbor))
+        .collect()
+    };
 
-    visited
+    flood(*self.borrow(), range, neighbors)
   }
 }
 
@@ -78,7 +62,7 @@ mod tests {
 
       set
 
-    };
+    }
 
     let result: HashSet<Point> = start.generic_flood(2, range_1d, &map);
 
@@ -0,0 +1,80 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+use enums::Zone;
+use structs::{Point, Prism};
+use traits::range::GenericReach;
+
+/// Trait wrapping zone flood implementation
+pub trait ZoneFlood: Borrow<Point> {
+  /// Find reachable points within a range through hostile zones of control
+  ///
+  /// Neighbors are provided by the range function as with `generic_flood`,
+  /// walls block movement as usual, and entering a controlled point either
+  /// ends movement or costs extra depending on the zone.
+  fn zone_flood<U: Borrow<Prism>>(
+    &self,
+    range: i32,
+    range_fn: fn(&Point, i32) -> HashSet<Point>,
+    zones: &HashSet<Point>,
+    zone: &Zone,
+    map: &HashMap<Point, U>,
+  ) -> HashSet<Point>;
+}
+
+impl<T> ZoneFlood for T where T: Borrow<Point> {
+  fn zone_flood<U: Borrow<Prism>>(
+    &self,
+    range: i32,
+    range_fn: fn(&Point, i32) -> HashSet<Point>,
+    zones: &HashSet<Point>,
+    zone: &Zone,
+    map: &HashMap<Point, U>,
+  ) -> HashSet<Point> {
+    let start: &Point = self.borrow();
+    let cost_fn = |from: &Point, to: &Point| zone.cost(start, from, to, zones);
+
+    self.generic_reach(range, range_fn, cost_fn, map).into_keys().collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use traits::IsPointMap;
+  use traits::range::{Base, BaseFlood};
+
+  #[test]
+  fn zone_flood() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+    let start: Point = Point(0, 0, 0);
+    let enemy: Point = Point(3, 0, 0);
+    let zones: HashSet<Point> = enemy.base_range(1);
+
+    map.insert_walled_point(Prism(Point(-1, 0, 0), 1, 0, 0, 0));
+
+    let free: HashSet<Point> = start.base_flood(3, &map);
+    let stop: HashSet<Point> = start.zone_flood(3, Base::base_range, &zones, &Zone::Stop, &map);
+    let cost: HashSet<Point> = start.zone_flood(3, Base::base_range, &zones, &Zone::Cost(1), &map);
+
+    assert!(free == start.zone_flood(3, Base::base_range, &HashSet::new(), &Zone::Stop, &map));
+    assert!(stop.contains(&Point(2, 0, 0)));
+    assert!(!stop.contains(&enemy));
+    assert!(stop.contains(&Point(3, -1, 0)));
+    assert!(cost.contains(&Point(2, 0, 0)));
+    assert!(!cost.contains(&Point(3, -1, 0)));
+    assert!(cost.len() < free.len());
+    assert!(stop.len() < free.len());
+  }
+
+  #[test]
+  fn zone_flood_start_in_zone() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let start: Point = Point(0, 0, 0);
+    let zones: HashSet<Point> = start.base_range(1);
+
+    let stop: HashSet<Point> = start.zone_flood(2, Base::base_range, &zones, &Zone::Stop, &map);
+
+    assert!(stop == zones);
+  }
+}
@@ -1,11 +1,25 @@
 mod base;
+mod cylinder;
+mod ellipsoid;
 mod flood;
 mod flood_base;
+mod flood_facing;
 mod flood_generic;
+mod flood_zone;
 mod range;
+mod reach;
+mod reach_base;
+mod reach_generic;
 
 pub use self::base::Base;
+pub use self::cylinder::Cylinder;
+pub use self::ellipsoid::Ellipsoid;
 pub use self::flood::Flood;
 pub use self::flood_base::BaseFlood;
+pub use self::flood_facing::FacingFlood;
 pub use self::flood_generic::GenericFlood;
+pub use self::flood_zone::ZoneFlood;
 pub use self::range::Range;
+pub use self::reach::Reach;
+pub use self::reach_base::BaseReach;
+pub use self::reach_generic::GenericReach;
@@ -1,29 +1,29 @@
 use std::borrow::Borrow;
 use std::collections::HashSet;
 
-use enums::Direction;
 use structs::Point;
-use traits::travel::Travel;
-use traits::range::Base;
+use structs::range::Iterator;
 
 /// Trait wrapping range implementation
 pub trait Range: Borrow<Point> {
   /// Find the points within the provided manhattan distance
   fn range(&self, range: i32) -> HashSet<Point>;
+
+  /// Iterate over the points within the provided manhattan distance
+  ///
+  /// Points are returned in layers, starting at the height of the point and
+  /// then alternating up and down. Each layer is returned in the order of
+  /// `Base::base_range_iter`.
+  fn range_iter(&self, range: i32) -> Iterator;
 }
 
 impl<T> Range for T where T: Borrow<Point> {
-  pub fn range(&self, range: i32) -> HashSet<Point> {
-    let mut set: HashSet<Point> = point.base_range(range);
-
-    for index in 1..range + 1 {
-      let diff = range - index;
-
-      set.extend(point.travel(&Direction::Up, index).base_range(diff));
-      set.extend(point.travel(&Direction::Down, index).base_range(diff));
-    }
+  fn range(&self, range: i32) -> HashSet<Point> {
+    self.range_iter(range).collect()
+  }
 
-    set
+  fn range_iter(&self, range: i32) -> Iterator {
+    Iterator::new(self, range, true)
   }
 }
 
@@ -47,4 +47,15 @@ mod tests {
     assert!(set.contains(&Point(1, 2, 6)));
     assert!(set.len() == 9);
   }
+
+  #[test]
+  fn range_iter() {
+    let point: Point = Point(1, 2, 5);
+    let vec: Vec<Point> = point.range_iter(1).collect();
+
+    assert!(Point(0, 3, 5) == vec[0]);
+    assert!(Point(1, 2, 6) == vec[7]);
+    assert!(Point(1, 2, 4) == vec[8]);
+    assert!(vec.len() == 9);
+  }
 }
@@ -0,0 +1,57 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use structs::{Point, Prism};
+use traits::range::{GenericReach, Range};
+
+/// Trait wrapping reach implementation
+pub trait Reach: Borrow<Point> {
+  /// Find reachable points within a budget
+  ///
+  /// The cost function provides the cost of stepping from one point to the
+  /// next, or `None` if the step is not allowed. Each reachable point is
+  /// mapped to the budget remaining there and the point it was reached from.
+  fn reach<U, F>(
+    &self,
+    budget: i32,
+    cost_fn: F,
+    map: &HashMap<Point, U>,
+  ) -> HashMap<Point, (i32, Option<Point>)>
+    where U: Borrow<Prism>, F: FnMut(&Point, &Point) -> Option<i32>;
+}
+
+impl<T> Reach for T where T: Borrow<Point> {
+  fn reach<U, F>(
+    &self,
+    budget: i32,
+    cost_fn: F,
+    map: &HashMap<Point, U>,
+  ) -> HashMap<Point, (i32, Option<Point>)>
+    where U: Borrow<Prism>, F: FnMut(&Point, &Point) -> Option<i32>
+  {
+    self.generic_reach(budget, Range::range, cost_fn, map)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reach() {
+    let map: HashMap<Point, Prism> = HashMap::new();
+    let start: Point = Point(1, 2, 5);
+
+    let climb = |from: &Point, to: &Point| match to.2 - from.2 {
+      1 => Some(2),
+      _ => Some(1),
+    };
+
+    let reach = start.reach(2, climb, &map);
+
+    assert!(reach[&Point(1, 2, 6)] == (0, Some(start)));
+    assert!(reach[&Point(1, 2, 3)] == (0, Some(Point(1, 2, 4))));
+    assert!(!reach.contains_key(&Point(1, 2, 7)));
+    assert!(reach.len() == 19 + 7 + 1 + 1);
+  }
+}
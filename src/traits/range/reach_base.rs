@@ -0,0 +1,69 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use structs::{Point, Prism};
+use traits::range::{Base, GenericReach};
+
+/// Trait wrapping base reach implementation
+pub trait BaseReach: Borrow<Point> {
+  /// Find reachable points of the same height within a budget
+  ///
+  /// The cost function provides the cost of stepping from one point to the
+  /// next, or `None` if the step is not allowed. Each reachable point is
+  /// mapped to the budget remaining there and the point it was reached from.
+  fn base_reach<U, F>(
+    &self,
+    budget: i32,
+    cost_fn: F,
+    map: &HashMap<Point, U>,
+  ) -> HashMap<Point, (i32, Option<Point>)>
+    where U: Borrow<Prism>, F: FnMut(&Point, &Point) -> Option<i32>;
+}
+
+impl<T> BaseReach for T where T: Borrow<Point> {
+  fn base_reach<U, F>(
+    &self,
+    budget: i32,
+    cost_fn: F,
+    map: &HashMap<Point, U>,
+  ) -> HashMap<Point, (i32, Option<Point>)>
+    where U: Borrow<Prism>, F: FnMut(&Point, &Point) -> Option<i32>
+  {
+    self.generic_reach(budget, Base::base_range, cost_fn, map)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use fns::search::backtrack;
+  use traits::IsPointMap;
+
+  #[test]
+  fn base_reach() {
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+    let start: Point = Point(0, 0, 0);
+    let swamp: Point = Point(1, 0, 0);
+
+    map.insert_walled_point(Prism(start, 0, 1, 0, 0));
+
+    let cost_fn = |_: &Point, to: &Point| match *to == swamp {
+      true => Some(3),
+      false => Some(1),
+    };
+
+    let reach = start.base_reach(2, cost_fn, &map);
+
+    assert!(reach[&start] == (2, None));
+    assert!(!reach.contains_key(&swamp));
+    assert!(reach[&Point(0, 1, 0)].0 == 0);
+    assert!(reach[&Point(2, -1, 0)] == (0, Some(Point(1, -1, 0))));
+    assert!(reach.values().all(|&(remaining, _)| remaining >= 0));
+    assert!(reach.keys().all(|point| point.2 == 0));
+
+    let path: Vec<Point> = backtrack(&reach, &Point(2, -1, 0)).unwrap();
+
+    assert!(path == vec![ start, Point(1, -1, 0), Point(2, -1, 0) ]);
+    assert!(start.base_reach(3, cost_fn, &map)[&swamp] == (0, Some(start)));
+  }
+}
@@ -0,0 +1,97 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+use fns::search::dijkstra;
+use structs::{Point, Prism};
+use traits::IsPointMap;
+
+/// Trait wrapping generic reach implementation
+pub trait GenericReach: Borrow<Point> {
+  /// Find reachable points within a budget with provided functions
+  ///
+  /// The range function provides the neighbors of a point, and the cost
+  /// function provides the cost of stepping from one point to the next, or
+  /// `None` if the step is not allowed. Each reachable point is mapped to the
+  /// budget remaining there and the point it was reached from, which
+  /// `fns::search::backtrack` follows back to the start.
+  fn generic_reach<U, F>(
+    &self,
+    budget: i32,
+    range_fn: fn(&Point, i32) -> HashSet<Point>,
+    cost_fn: F,
+    map: &HashMap<Point, U>,
+  ) -> HashMap<Point, (i32, Option<Point>)>
+    where U: Borrow<Prism>, F: FnMut(&Point, &Point) -> Option<i32>;
+}
+
+impl<T> GenericReach for T where T: Borrow<Point> {
+  fn generic_reach<U, F>(
+    &self,
+    budget: i32,
+    range_fn: fn(&Point, i32) -> HashSet<Point>,
+    mut cost_fn: F,
+    map: &HashMap<Point, U>,
+  ) -> HashMap<Point, (i32, Option<Point>)>
+    where U: Borrow<Prism>, F: FnMut(&Point, &Point) -> Option<i32>
+  {
+    let neighbors = |point: &Point| {
+      let mut neighbors: Vec<(Point, i32)> = Vec::new();
+
+      for neighbor in range_fn(point, 1) {
+        if neighbor == *point || map.has_wall_between(point, &neighbor) {
+          continue;
+        }
+
+        if let Some(cost) = cost_fn(point, &neighbor) {
+          neighbors.push((neighbor, cost));
+        }
+      }
+
+      neighbors
+    };
+
+    dijkstra(*self.borrow(), budget, neighbors)
+      .into_iter()
+      .map(|(point, (cost, previous))| (point, (budget - cost, previous)))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+  use traits::travel::Travel;
+
+  #[test]
+  fn generic_reach() {
+    let start: Point = Point(0, 0, 0);
+    let wall: Point = Point(0, 0, 2);
+
+    let mut map: HashMap<Point, Prism> = HashMap::new();
+
+    map.insert(wall, Prism(wall, 0, 0, 0, 1));
+
+    fn range_1d(point: &Point, range: i32) -> HashSet<Point> {
+      let mut set: HashSet<Point> = HashSet::new();
+
+      set.insert(point.travel(&Up, range));
+      set.insert(point.travel(&Down, range));
+
+      set
+    }
+
+    let cost_fn = |_: &Point, to: &Point| match to.2 {
+      t if t < 0 => Some(2),
+      _ => Some(1),
+    };
+
+    let reach = start.generic_reach(4, range_1d, cost_fn, &map);
+
+    assert!(reach[&start] == (4, None));
+    assert!(reach[&Point(0, 0, 1)] == (3, Some(start)));
+    assert!(reach[&Point(0, 0, -2)] == (0, Some(Point(0, 0, -1))));
+    assert!(!reach.contains_key(&wall));
+    assert!(reach.len() == 4);
+  }
+}
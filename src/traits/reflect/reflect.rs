@@ -0,0 +1,84 @@
+use std::borrow::Borrow;
+
+use enums::Axis;
+use structs::Point;
+
+/// Trait wrapping reflect implementation
+pub trait Reflect: Borrow<Point> {
+  /// Reflect the point across an axis through a provided center
+  ///
+  /// The height of the point is not changed. Reflecting twice across the same
+  /// axis brings a point back to the starting position.
+  fn reflect<U: Borrow<Point>>(&self, center: &U, axis: &Axis) -> Point;
+}
+
+impl<T> Reflect for T where T: Borrow<Point> {
+  fn reflect<U: Borrow<Point>>(&self, center: &U, axis: &Axis) -> Point {
+    let point = self.borrow();
+    let center = center.borrow();
+
+    let relative_point = point - center;
+    let Point(q, r, t) = relative_point;
+    let s = relative_point.s();
+
+    let reflected_point: Point = match *axis {
+      Axis::Q     => Point( q,  s, t),
+      Axis::R     => Point( s,  r, t),
+      Axis::S     => Point( r,  q, t),
+      Axis::AntiQ => Point(-q, -s, t),
+      Axis::AntiR => Point(-s, -r, t),
+      Axis::AntiS => Point(-r, -q, t),
+    };
+
+    &reflected_point + center
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction;
+  use traits::distance::Distance;
+  use traits::travel::Travel;
+
+  #[test]
+  fn reflect() {
+    let point: Point = Point(4, 3, 5);
+    let center: Point = Point(1, 2, 5);
+
+    assert!(Point( 4, -2, 5) == point.reflect(&center, &Axis::Q));
+    assert!(Point(-3,  3, 5) == point.reflect(&center, &Axis::R));
+    assert!(Point( 2,  5, 5) == point.reflect(&center, &Axis::S));
+    assert!(Point(-2,  6, 5) == point.reflect(&center, &Axis::AntiQ));
+    assert!(Point( 5,  1, 5) == point.reflect(&center, &Axis::AntiR));
+    assert!(Point( 0, -1, 5) == point.reflect(&center, &Axis::AntiS));
+  }
+
+  #[test]
+  fn reflect_twice() {
+    let point: Point = Point(3, 1, 7);
+    let center: Point = Point(1, 2, 5);
+
+    for axis in &Axis::to_vec() {
+      let reflected: Point = point.reflect(&center, axis);
+
+      assert!(point == reflected.reflect(&center, axis));
+      assert!(point.distance(&center) == reflected.distance(&center));
+    }
+  }
+
+  #[test]
+  fn reflect_keeps_axis() {
+    let center: Point = Point(1, 2, 5);
+
+    let axes: Vec<(Axis, Point)> = vec![
+      (Axis::AntiQ, center.travel(&Direction::Southeast, 2)),
+      (Axis::AntiR, center.travel(&Direction::East, 2)),
+      (Axis::AntiS, center.travel(&Direction::Northeast, 2)),
+    ];
+
+    for &(axis, point) in &axes {
+      assert!(point == point.reflect(&center, &axis));
+    }
+  }
+}
@@ -1,29 +1,27 @@
 use std::borrow::Borrow;
 use std::collections::HashSet;
 
-use enums::Direction;
 use structs::Point;
-use traits::travel::Travel;
+use structs::ring::Iterator;
 
 /// Trait wrapping base ring implementation
 pub trait Base: Borrow<Point> {
   /// Find points at the same height in a ring of a provided radius
   fn base_ring(&self, range: i32) -> HashSet<Point>;
+
+  /// Iterate over points at the same height in a ring of a provided radius
+  ///
+  /// The ring starts at the northwest corner and moves clockwise.
+  fn base_ring_iter(&self, range: i32) -> Iterator;
 }
 
 impl<T> Base for T where T: Borrow<Point> {
   fn base_ring(&self, range: i32) -> HashSet<Point> {
-    let mut set: HashSet<Point> = HashSet::new();
-    let mut point: Point = self.travel(&Direction::Northwest, range);
-
-    for direction in Direction::to_vec().iter().take(6) {
-      for _ in 0..range {
-        set.insert(point);
-        point = point.travel(direction, 1);
-      }
-    }
+    self.base_ring_iter(range).collect()
+  }
 
-    set
+  fn base_ring_iter(&self, range: i32) -> Iterator {
+    Iterator::new(self, range)
   }
 }
 
@@ -44,4 +42,15 @@ mod tests {
     assert!(set.contains(&Point(0, 2, 5)));
     assert!(set.len() == 6);
   }
+
+  #[test]
+  fn base_ring_iter() {
+    let point: Point = Point(1, 2, 5);
+    let vec: Vec<Point> = point.base_ring_iter(2).collect();
+
+    assert!(Point(1, 0, 5) == vec[0]);
+    assert!(Point(2, 0, 5) == vec[1]);
+    assert!(Point(0, 1, 5) == vec[11]);
+    assert!(vec.len() == 12);
+  }
 }
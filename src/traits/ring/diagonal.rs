@@ -0,0 +1,45 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use enums::Diagonal;
+use structs::Point;
+use traits::travel::DiagonalTravel;
+
+/// Trait wrapping diagonal neighbors implementation
+pub trait DiagonalNeighbors: Borrow<Point> {
+  /// Find the points at the same height one unit away in each diagonal
+  fn diagonal_neighbors(&self) -> HashSet<Point>;
+}
+
+impl<T> DiagonalNeighbors for T where T: Borrow<Point> {
+  fn diagonal_neighbors(&self) -> HashSet<Point> {
+    Diagonal::to_vec()
+      .iter()
+      .map(|diagonal| self.diagonal_travel(diagonal, 1))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use traits::distance::Base;
+
+  #[test]
+  fn diagonal_neighbors() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.diagonal_neighbors();
+
+    assert!(set.contains(&Point(2, 3, 5)));
+    assert!(set.contains(&Point(0, 4, 5)));
+    assert!(set.contains(&Point(-1, 3, 5)));
+    assert!(set.contains(&Point(0, 1, 5)));
+    assert!(set.contains(&Point(2, 0, 5)));
+    assert!(set.contains(&Point(3, 1, 5)));
+    assert!(set.len() == 6);
+
+    for neighbor in &set {
+      assert!(2 == point.base_distance(neighbor));
+    }
+  }
+}
@@ -1,5 +1,7 @@
 mod base;
+mod diagonal;
 mod ring;
 
 pub use self::base::Base;
+pub use self::diagonal::DiagonalNeighbors;
 pub use self::ring::Ring;
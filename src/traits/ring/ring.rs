@@ -1,32 +1,29 @@
 use std::borrow::Borrow;
 use std::collections::HashSet;
 
-use enums::Direction;
 use structs::Point;
-use traits::ring::Base;
-use traits::travel::Travel;
+use structs::ring::Shell;
 
 /// Trait wrapping ring implementation
 pub trait Ring: Borrow<Point> {
   /// Find points in a spherical ring of a provided radius
   fn ring(&self, range: i32) -> HashSet<Point>;
+
+  /// Iterate over points in a spherical ring of a provided radius
+  ///
+  /// Points are returned in rings, starting at the height of the point and
+  /// then alternating up and down. Each ring is returned in the order of
+  /// `Base::base_ring_iter`, and the points directly above and below are last.
+  fn ring_iter(&self, range: i32) -> Shell;
 }
 
 impl<T> Ring for T where T: Borrow<Point> {
   fn ring(&self, range: i32) -> HashSet<Point> {
-    let mut set: HashSet<Point> = self.base_ring(range);
-
-    for index in 1..range + 1 {
-      let diff = range - index;
-
-      set.extend(self.travel(&Direction::Up, index).base_ring(diff));
-      set.extend(self.travel(&Direction::Down, index).base_ring(diff));
-    }
-
-    set.insert(self.travel(&Direction::Up, range));
-    set.insert(self.travel(&Direction::Down, range));
+    self.ring_iter(range).collect()
+  }
 
-    set
+  fn ring_iter(&self, range: i32) -> Shell {
+    Shell::new(self, range, true)
   }
 }
 
@@ -70,4 +67,17 @@ mod tests {
     assert!(set.contains(&Point(1, 2, 3)));
     assert!(set.len() == 26);
   }
+
+  #[test]
+  fn ring_iter() {
+    let point: Point = Point(1, 2, 5);
+    let vec: Vec<Point> = point.ring_iter(2).collect();
+
+    assert!(Point(1, 0, 5) == vec[0]);
+    assert!(Point(1, 1, 6) == vec[12]);
+    assert!(Point(1, 1, 4) == vec[18]);
+    assert!(Point(1, 2, 7) == vec[24]);
+    assert!(Point(1, 2, 3) == vec[25]);
+    assert!(vec.len() == 26);
+  }
 }
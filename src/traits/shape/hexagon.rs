@@ -0,0 +1,41 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use enums::Direction;
+use structs::Point;
+use traits::range::Base;
+use traits::travel::Travel;
+
+/// Trait wrapping hexagon implementation
+pub trait Hexagon: Borrow<Point> {
+  /// Find the points in a hexagon centered on the point
+  ///
+  /// The shape is repeated on the provided number of layers, starting at the
+  /// height of the point and moving up.
+  fn hexagon(&self, radius: i32, layers: i32) -> HashSet<Point>;
+}
+
+impl<T> Hexagon for T where T: Borrow<Point> {
+  fn hexagon(&self, radius: i32, layers: i32) -> HashSet<Point> {
+    (0..layers)
+      .flat_map(|layer| self.travel(&Direction::Up, layer).base_range_iter(radius))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn hexagon() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.hexagon(2, 3);
+
+    assert!(set.contains(&Point(1, 2, 5)));
+    assert!(set.contains(&Point(3, 2, 7)));
+    assert!(!set.contains(&Point(1, 2, 4)));
+    assert!(!set.contains(&Point(1, 2, 8)));
+    assert!(set.len() == 57);
+  }
+}
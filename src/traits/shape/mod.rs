@@ -0,0 +1,9 @@
+mod hexagon;
+mod parallelogram;
+mod rectangle;
+mod triangle;
+
+pub use self::hexagon::Hexagon;
+pub use self::parallelogram::Parallelogram;
+pub use self::rectangle::Rectangle;
+pub use self::triangle::Triangle;
@@ -0,0 +1,82 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use enums::Direction;
+use structs::Point;
+use traits::travel::Travel;
+
+/// Trait wrapping parallelogram implementation
+pub trait Parallelogram: Borrow<Point> {
+  /// Find the points in a parallelogram with a corner at the point
+  ///
+  /// The sides of the parallelogram follow two directions, with the provided
+  /// number of points along each side. The shape is repeated on the provided
+  /// number of layers, starting at the height of the point and moving up.
+  fn parallelogram(
+    &self,
+    first: &Direction,
+    width: i32,
+    second: &Direction,
+    length: i32,
+    layers: i32,
+  ) -> HashSet<Point>;
+}
+
+impl<T> Parallelogram for T where T: Borrow<Point> {
+  fn parallelogram(
+    &self,
+    first: &Direction,
+    width: i32,
+    second: &Direction,
+    length: i32,
+    layers: i32,
+  ) -> HashSet<Point> {
+    let mut set: HashSet<Point> = HashSet::new();
+
+    for layer in 0..layers {
+      let corner: Point = self.travel(&Direction::Up, layer);
+
+      for index in 0..width {
+        let start: Point = corner.travel(first, index);
+
+        for other in 0..length {
+          set.insert(start.travel(second, other));
+        }
+      }
+    }
+
+    set
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+
+  #[test]
+  fn parallelogram() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.parallelogram(&East, 3, &Southeast, 2, 1);
+
+    assert!(set.contains(&Point(1, 2, 5)));
+    assert!(set.contains(&Point(2, 2, 5)));
+    assert!(set.contains(&Point(3, 2, 5)));
+    assert!(set.contains(&Point(1, 3, 5)));
+    assert!(set.contains(&Point(2, 3, 5)));
+    assert!(set.contains(&Point(3, 3, 5)));
+    assert!(set.len() == 6);
+  }
+
+  #[test]
+  fn parallelogram_layers() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.parallelogram(&Northeast, 2, &West, 2, 2);
+
+    assert!(set.contains(&Point(2, 1, 5)));
+    assert!(set.contains(&Point(0, 2, 6)));
+    assert!(set.contains(&Point(1, 1, 6)));
+    assert!(!set.contains(&Point(1, 2, 7)));
+    assert!(set.len() == 8);
+  }
+}
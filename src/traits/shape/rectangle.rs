@@ -0,0 +1,76 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use enums::Direction;
+use structs::Point;
+use traits::travel::Travel;
+
+/// Trait wrapping rectangle implementation
+pub trait Rectangle: Borrow<Point> {
+  /// Find the points in a rectangle with its northwest corner at the point
+  ///
+  /// Rows run from west to east and are stacked from north to south. Every
+  /// second row is shifted half a point east so the rectangle keeps straight
+  /// sides. The shape is repeated on the provided number of layers, starting
+  /// at the height of the point and moving up.
+  fn rectangle(&self, columns: i32, rows: i32, layers: i32) -> HashSet<Point>;
+}
+
+impl<T> Rectangle for T where T: Borrow<Point> {
+  fn rectangle(&self, columns: i32, rows: i32, layers: i32) -> HashSet<Point> {
+    let mut set: HashSet<Point> = HashSet::new();
+
+    for layer in 0..layers {
+      let corner: Point = self.travel(&Direction::Up, layer);
+
+      for row in 0..rows {
+        let start: Point = corner
+          .travel(&Direction::Southeast, row)
+          .travel(&Direction::West, row / 2);
+
+        for column in 0..columns {
+          set.insert(start.travel(&Direction::East, column));
+        }
+      }
+    }
+
+    set
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use structs::PixelPoint;
+
+  #[test]
+  fn rectangle() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.rectangle(2, 3, 1);
+
+    assert!(set.contains(&Point(1, 2, 5)));
+    assert!(set.contains(&Point(2, 2, 5)));
+    assert!(set.contains(&Point(1, 3, 5)));
+    assert!(set.contains(&Point(2, 3, 5)));
+    assert!(set.contains(&Point(0, 4, 5)));
+    assert!(set.contains(&Point(1, 4, 5)));
+    assert!(set.len() == 6);
+  }
+
+  #[test]
+  fn rectangle_straight_sides() {
+    let point: Point = Point(0, 0, 0);
+    let set: HashSet<Point> = point.rectangle(4, 6, 2);
+    let left = |point: &Point| {
+      let PixelPoint(x, _) = Point::<f32>::from(*point).into();
+
+      x
+    };
+
+    let min = set.iter().map(&left).fold(f32::MAX, f32::min);
+    let max = set.iter().map(&left).fold(f32::MIN, f32::max);
+
+    assert!(((max - min) - 3.5f32 * 3f32.sqrt()).abs() < 1e-5);
+    assert!(set.len() == 48);
+  }
+}
@@ -0,0 +1,102 @@
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+use enums::Direction;
+use structs::Point;
+use traits::travel::Travel;
+
+/// Trait wrapping triangle implementation
+pub trait Triangle: Borrow<Point> {
+  /// Find the points in a triangle with a corner at the point
+  ///
+  /// Two sides of the triangle follow two directions, with the provided number
+  /// of points along each side. Directions which are next to each other make
+  /// an equilateral triangle. The shape is repeated on the provided number of
+  /// layers, starting at the height of the point and moving up.
+  fn triangle(
+    &self,
+    first: &Direction,
+    second: &Direction,
+    size: i32,
+    layers: i32,
+  ) -> HashSet<Point>;
+}
+
+impl<T> Triangle for T where T: Borrow<Point> {
+  fn triangle(
+    &self,
+    first: &Direction,
+    second: &Direction,
+    size: i32,
+    layers: i32,
+  ) -> HashSet<Point> {
+    let mut set: HashSet<Point> = HashSet::new();
+
+    for layer in 0..layers {
+      let corner: Point = self.travel(&Direction::Up, layer);
+
+      for index in 0..size {
+        let start: Point = corner.travel(first, index);
+
+        for other in 0..size - index {
+          set.insert(start.travel(second, other));
+        }
+      }
+    }
+
+    set
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use enums::Direction::*;
+  use traits::distance::Base;
+
+  #[test]
+  fn triangle() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.triangle(&East, &Southeast, 3, 1);
+
+    assert!(set.contains(&Point(1, 2, 5)));
+    assert!(set.contains(&Point(2, 2, 5)));
+    assert!(set.contains(&Point(3, 2, 5)));
+    assert!(set.contains(&Point(1, 3, 5)));
+    assert!(set.contains(&Point(2, 3, 5)));
+    assert!(set.contains(&Point(1, 4, 5)));
+    assert!(set.len() == 6);
+  }
+
+  #[test]
+  fn triangle_equilateral() {
+    let point: Point = Point(1, 2, 5);
+    let set: HashSet<Point> = point.triangle(&West, &Northwest, 4, 2);
+    let corners: Vec<Point> = vec![
+      point,
+      point.travel(&West, 3),
+      point.travel(&Northwest, 3),
+    ];
+
+    for corner in &corners {
+      for other in &corners {
+        assert!(corner == other || 3 == corner.base_distance(other));
+      }
+    }
+
+    let mut expected: HashSet<Point> = HashSet::new();
+
+    for t in 5..7 {
+      for q in -2..2 {
+        for r in -1..3 {
+          if (1 - q) + (2 - r) <= 3 {
+            expected.insert(Point(q, r, t));
+          }
+        }
+      }
+    }
+
+    assert!(set == expected);
+    assert!(set.len() == 20);
+  }
+}
@@ -0,0 +1,38 @@
+use std::borrow::Borrow;
+
+use structs::Point;
+use structs::spiral::Iterator;
+
+/// Trait wrapping base spiral implementation
+pub trait Base: Borrow<Point> {
+  /// Iterate over points at the same height in a spiral of a provided radius
+  ///
+  /// The center is first, followed by each ring moving outward. Each ring
+  /// starts at the northwest corner and moves clockwise.
+  fn base_spiral(&self, range: i32) -> Iterator;
+}
+
+impl<T> Base for T where T: Borrow<Point> {
+  fn base_spiral(&self, range: i32) -> Iterator {
+    Iterator::new(self, range, false)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn base_spiral() {
+    let point: Point = Point(1, 2, 5);
+    let vec: Vec<Point> = point.base_spiral(2).collect();
+
+    assert!(Point(1, 2, 5) == vec[0]);
+    assert!(Point(1, 1, 5) == vec[1]);
+    assert!(Point(0, 2, 5) == vec[6]);
+    assert!(Point(1, 0, 5) == vec[7]);
+    assert!(Point(2, 0, 5) == vec[8]);
+    assert!(Point(0, 1, 5) == vec[18]);
+    assert!(vec.len() == 19);
+  }
+}
@@ -0,0 +1,5 @@
+mod base;
+mod spiral;
+
+pub use self::base::Base;
+pub use self::spiral::Spiral;
@@ -0,0 +1,42 @@
+use std::borrow::Borrow;
+
+use structs::Point;
+use structs::spiral::Iterator;
+
+/// Trait wrapping spiral implementation
+pub trait Spiral: Borrow<Point> {
+  /// Iterate over points in a spherical spiral of a provided radius
+  ///
+  /// The center is first, followed by each spherical ring moving outward.
+  /// Each spherical ring starts with the ring at the height of the center and
+  /// then alternates between the rings above and below it.
+  fn spiral(&self, range: i32) -> Iterator;
+}
+
+impl<T> Spiral for T where T: Borrow<Point> {
+  fn spiral(&self, range: i32) -> Iterator {
+    Iterator::new(self, range, true)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn spiral() {
+    let point: Point = Point(1, 2, 5);
+    let vec: Vec<Point> = point.spiral(1).collect();
+
+    assert!(Point(1, 2, 5) == vec[0]);
+    assert!(Point(1, 1, 5) == vec[1]);
+    assert!(Point(2, 1, 5) == vec[2]);
+    assert!(Point(2, 2, 5) == vec[3]);
+    assert!(Point(1, 3, 5) == vec[4]);
+    assert!(Point(0, 3, 5) == vec[5]);
+    assert!(Point(0, 2, 5) == vec[6]);
+    assert!(Point(1, 2, 6) == vec[7]);
+    assert!(Point(1, 2, 4) == vec[8]);
+    assert!(vec.len() == 9);
+  }
+}
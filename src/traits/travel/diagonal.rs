@@ -0,0 +1,43 @@
+use std::borrow::Borrow;
+
+use enums::Diagonal;
+use enums::Diagonal::*;
+use structs::Point;
+
+/// Trait wrapping diagonal travel implementation
+pub trait DiagonalTravel: Borrow<Point> {
+  /// Create a point in the specified diagonal a specified number of units
+  fn diagonal_travel(&self, diagonal: &Diagonal, units: i32) -> Point;
+}
+
+impl<T> DiagonalTravel for T where T: Borrow<Point> {
+  fn diagonal_travel(&self, diagonal: &Diagonal, units: i32) -> Point {
+    let &Point(q, r, t) = self.borrow();
+
+    match *diagonal {
+      EastSoutheast => Point(q + units    , r + units    , t),
+      South         => Point(q - units    , r + units * 2, t),
+      WestSouthwest => Point(q - units * 2, r + units    , t),
+      WestNorthwest => Point(q - units    , r - units    , t),
+      North         => Point(q + units    , r - units * 2, t),
+      EastNortheast => Point(q + units * 2, r - units    , t),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn diagonal_travel() {
+    let point: Point = Point(1, 2, 5);
+
+    assert!(Point( 3,  4, 5) == point.diagonal_travel(&EastSoutheast, 2));
+    assert!(Point(-1,  6, 5) == point.diagonal_travel(&South        , 2));
+    assert!(Point(-3,  4, 5) == point.diagonal_travel(&WestSouthwest, 2));
+    assert!(Point(-1,  0, 5) == point.diagonal_travel(&WestNorthwest, 2));
+    assert!(Point( 3, -2, 5) == point.diagonal_travel(&North        , 2));
+    assert!(Point( 5,  0, 5) == point.diagonal_travel(&EastNortheast, 2));
+  }
+}
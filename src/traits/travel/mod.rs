@@ -1,3 +1,5 @@
+mod diagonal;
 mod travel;
 
+pub use self::diagonal::DiagonalTravel;
 pub use self::travel::Travel;